@@ -0,0 +1,29 @@
+//! Benchmarks every scaffolded day against its real input with criterion.
+//!
+//! Run with `cargo bench --features bench`, optionally filtered by day, e.g. `-- day13`.
+//! Afterwards, `cargo time --from-bench --store` writes the results to the readme.
+use advent_of_code::template::try_read_file;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+fn bench_days(c: &mut Criterion) {
+    for day in days() {
+        let input = match try_read_file("inputs", day.day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Skipping day {}: {e}", day.day);
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(day.group_name());
+        for step in &day.steps {
+            group.bench_function(step.name, |b| b.iter(|| (step.run)(&input)));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Generates the day registry of the `cargo bench` harness in `benches/days.rs`.
+//! Only does work when the `bench` feature is enabled.
+use std::{env, fs, path::PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_BENCH").is_none() {
+        return;
+    }
+
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let solutions = root.join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", solutions.display());
+
+    let mut days: Vec<u8> = fs::read_dir(&solutions)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let name = entry.ok()?.file_name().into_string().ok()?;
+                    let day: u8 = name.strip_suffix(".rs")?.parse().ok()?;
+                    (1..=25).contains(&day).then_some(day)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    days.sort_unstable();
+
+    let mut registry = String::new();
+    for day in &days {
+        let path = solutions.join(format!("{day:02}.rs"));
+        registry.push_str(&format!(
+            "#[path = {:?}]\n#[allow(dead_code, unused_imports)]\nmod day{day:02};\n",
+            path.display().to_string()
+        ));
+    }
+
+    registry.push_str("\n/// The benchmarks of every scaffolded day.\nfn days() -> Vec<advent_of_code::template::bench::DayBench> {\n    vec![");
+    for day in &days {
+        registry.push_str(&format!("day{day:02}::__bench(), "));
+    }
+    registry.push_str("]\n}\n");
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out, registry).unwrap();
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::days::Solution;
+use crate::template::{Day, ParseError};
+
+pub const DAY: Day = crate::day!(1);
+
+pub struct Day01;
+
+/// Parse each line into a pair of location ids
+pub fn parse_pairs(input: &str) -> Result<Vec<(u32, u32)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut ids = line.split_whitespace().map(|n| {
+                n.parse::<u32>()
+                    .map_err(|e| ParseError::in_input(input, n, format!("`{n}`: {e}")))
+            });
+            match (ids.next(), ids.next(), ids.next()) {
+                (Some(a), Some(b), None) => Ok((a?, b?)),
+                _ => Err(ParseError::on_line(i + 1, "expected two location ids")),
+            }
+        })
+        .collect()
+}
+
+impl Solution for Day01 {
+    type Input = Result<Vec<(u32, u32)>, ParseError>;
+    type PartOne = Result<u32, ParseError>;
+    type PartTwo = Result<u32, ParseError>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_pairs(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        // Break input out into two lists
+        let (mut list1, mut list2): (Vec<u32>, Vec<u32>) = input
+            .as_ref()
+            .map_err(Clone::clone)?
+            .iter()
+            .copied()
+            .unzip();
+
+        // Sort the lists
+        list1.sort_unstable();
+        list2.sort_unstable();
+
+        // Now pair in sorted order (smallest with smallest, etc)
+        let result = list1
+            .into_iter()
+            .zip(list2)
+            .map(|(a, b)| b.abs_diff(a))
+            .sum();
+
+        Ok(result)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        // Split input into pairs and separate into list and frequency map
+        let mut list1 = Vec::new();
+        let mut freq_map = HashMap::new();
+
+        for &(a, b) in input.as_ref().map_err(Clone::clone)? {
+            list1.push(a);
+            *freq_map.entry(b).or_insert(0) += 1;
+        }
+
+        // sum the list, multiplied by the frequency of the number in the map, returning 0 if the number is not in the map
+        let result = list1
+            .iter()
+            .map(|n| n * freq_map.get(n).unwrap_or(&0))
+            .sum();
+        Ok(result)
+    }
+}
+
+pub fn parse(input: &str) -> <Day01 as Solution>::Input {
+    Day01::parse(input)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    Day01::part_one(&Day01::parse(input))
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    Day01::part_two(&Day01::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        // The result should be 11
+        assert_eq!(result, Ok(11));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Ok(31));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let result = part_one("3   4\n4   x3\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 5: `x3`: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::days::Solution;
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(2);
+
+pub struct Day02;
+
+/// Parse each line into a report of levels
+pub fn parse_reports(input: &str) -> Vec<Vec<u32>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|n| n.parse::<u32>().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+pub fn is_safe_sequence(levels: &[u32]) -> bool {
+    if levels.len() <= 1 {
+        return false;
+    }
+
+    let mut increasing = true;
+    let mut decreasing = true;
+
+    for pair in levels.windows(2) {
+        let diff = pair[1] as i32 - pair[0] as i32;
+        let abs_diff = diff.abs();
+
+        if !(1..=3).contains(&abs_diff) {
+            return false;
+        }
+
+        if diff > 0 {
+            decreasing = false;
+        } else {
+            increasing = false;
+        }
+
+        if !increasing && !decreasing {
+            return false;
+        }
+    }
+
+    true
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<u32>>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_reports(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let result = input
+            .iter()
+            .filter(|levels| is_safe_sequence(levels))
+            .count() as u32;
+
+        Some(result)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let result = input
+            .iter()
+            .map(|levels| {
+                // First check if sequence is already safe
+                if is_safe_sequence(levels) {
+                    return 1;
+                }
+
+                // Try removing each number and check if resulting sequence is safe
+                for i in 0..levels.len() {
+                    let mut test_levels = levels.clone();
+                    test_levels.remove(i);
+                    if is_safe_sequence(&test_levels) {
+                        return 1;
+                    }
+                }
+
+                0
+            })
+            .sum();
+
+        Some(result)
+    }
+}
+
+pub fn parse(input: &str) -> <Day02 as Solution>::Input {
+    Day02::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day02::part_one(&Day02::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day02::part_two(&Day02::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(4));
+    }
+}
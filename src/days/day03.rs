@@ -0,0 +1,102 @@
+use regex::Regex;
+
+use crate::days::Solution;
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(3);
+
+pub struct Day03;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+/// Parse the input for `mul(x,y)`, `do()` and `don't()` instructions, skipping corrupted memory.
+pub fn parse_instructions(input: &str) -> Vec<Instruction> {
+    let regex = Regex::new(r"(?:don't\(\)|do\(\)|mul\((\d+),(\d+)\))").unwrap();
+
+    regex
+        .captures_iter(input)
+        .map(|cap| match cap.get(0).unwrap().as_str() {
+            "don't()" => Instruction::Dont,
+            "do()" => Instruction::Do,
+            _ => {
+                // Must be a multiplication match
+                let x = cap.get(1).unwrap().as_str().parse::<u32>().unwrap();
+                let y = cap.get(2).unwrap().as_str().parse::<u32>().unwrap();
+                Instruction::Mul(x, y)
+            }
+        })
+        .collect()
+}
+
+impl Solution for Day03 {
+    type Input = Vec<Instruction>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_instructions(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        // Multiply x and y of every instruction and add to result
+        let mut result: u32 = 0;
+        for instruction in input {
+            if let Instruction::Mul(x, y) = instruction {
+                result += x * y;
+            }
+        }
+        Some(result)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let mut result: u32 = 0;
+        let mut skip = false;
+
+        for instruction in input {
+            match instruction {
+                Instruction::Dont => skip = true,
+                Instruction::Do => skip = false,
+                Instruction::Mul(x, y) => {
+                    if !skip {
+                        result += x * y;
+                    }
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+pub fn parse(input: &str) -> <Day03 as Solution>::Input {
+    Day03::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day03::part_one(&Day03::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day03::part_two(&Day03::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(161));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file_part("examples", DAY, 2));
+        assert_eq!(result, Some(48));
+    }
+}
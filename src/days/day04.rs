@@ -0,0 +1,97 @@
+use crate::days::Solution;
+use crate::grid::Grid;
+use crate::point::{Dir8, Point};
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(4);
+
+pub struct Day04;
+
+/// Split input into a matrix
+pub fn parse_grid(input: &str) -> Grid<char> {
+    Grid::parse(input, |c| c).expect("the input should be a grid of letters")
+}
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_grid(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let grid = input;
+
+        // Starting at every `X`, read four letters in each of the 8 directions
+        let count = grid
+            .find_all(|&c| c == 'X')
+            .flat_map(|pos| Dir8::ALL.map(|dir| (pos, dir)))
+            .filter(|&(pos, dir)| {
+                grid.ray(pos, dir.into())
+                    .take(4)
+                    .copied()
+                    .eq("XMAS".chars())
+            })
+            .count();
+
+        Some(count as u32)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let grid = input;
+
+        let count = grid
+            .find_all(|&c| c == 'A')
+            .filter(|&pos| {
+                let cell = |dir: Dir8| grid.get(Point::from(pos) + dir).copied();
+
+                // Check left-right diagonal (top_left with bottom_right)
+                let valid_lr = matches!(
+                    (cell(Dir8::NorthWest), cell(Dir8::SouthEast)),
+                    (Some('M'), Some('S')) | (Some('S'), Some('M'))
+                );
+
+                // Check right-left diagonal (top_right with bottom_left)
+                let valid_rl = matches!(
+                    (cell(Dir8::NorthEast), cell(Dir8::SouthWest)),
+                    (Some('M'), Some('S')) | (Some('S'), Some('M'))
+                );
+
+                valid_lr && valid_rl
+            })
+            .count();
+
+        Some(count as u32)
+    }
+}
+
+pub fn parse(input: &str) -> <Day04 as Solution>::Input {
+    Day04::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day04::part_one(&Day04::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day04::part_two(&Day04::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(9));
+    }
+}
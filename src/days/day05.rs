@@ -0,0 +1,182 @@
+use petgraph::algo::toposort;
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::collections::HashMap;
+
+use crate::days::Solution;
+use crate::parse::{ints, sections, Pattern};
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(5);
+
+pub struct Day05;
+
+/// The page ordering rules and the updates of the safety manual.
+pub struct Manual {
+    pub rules: Vec<(u32, u32)>,
+    pub updates: Vec<Vec<u32>>,
+}
+
+pub fn parse_manual(input: &str) -> Manual {
+    let mut sections = sections(input);
+    let rule = Pattern::new("{}|{}");
+
+    let rules = sections
+        .next()
+        .unwrap()
+        .lines()
+        .map(|line| rule.parse(line).unwrap())
+        .collect();
+
+    let updates = sections
+        .next()
+        .unwrap()
+        .lines()
+        .map(|update| ints(update).collect())
+        .collect();
+
+    Manual { rules, updates }
+}
+
+impl Solution for Day05 {
+    type Input = Manual;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_manual(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let mut middle_nodes = Vec::new();
+        for sequence in &input.updates {
+            // Only build graph with rules relevant to this sequence
+            let filtered_rules = filter_rules(&input.rules, sequence);
+            let (graph, node_map) = build_graph(&filtered_rules);
+
+            if validate_sequence(&graph, sequence, &node_map) {
+                let middle = sequence.len() / 2;
+                middle_nodes.push(sequence[middle]);
+            }
+        }
+
+        Some(middle_nodes.iter().sum())
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let mut middle_nodes = Vec::new();
+        for sequence in &input.updates {
+            // Only build graph with rules relevant to this sequence
+            let filtered_rules = filter_rules(&input.rules, sequence);
+            let (graph, node_map) = build_graph(&filtered_rules);
+
+            if !validate_sequence(&graph, sequence, &node_map) {
+                let order = match toposort(&graph, None) {
+                    Ok(order) => order.into_iter().map(|idx| graph[idx]).collect::<Vec<_>>(),
+                    Err(_) => sequence.clone(), // If cyclic, keep original order
+                };
+
+                let order_map: HashMap<u32, usize> =
+                    order.iter().enumerate().map(|(i, &val)| (val, i)).collect();
+
+                let mut sorted_sequence = sequence.clone();
+                sorted_sequence.sort_by(|&a, &b| match (order_map.get(&a), order_map.get(&b)) {
+                    (Some(ord_a), Some(ord_b)) => ord_a.cmp(ord_b),
+                    (Some(_), None) => std::cmp::Ordering::Less,
+                    (None, Some(_)) => std::cmp::Ordering::Greater,
+                    (None, None) => sequence
+                        .iter()
+                        .position(|&x| x == a)
+                        .cmp(&sequence.iter().position(|&x| x == b)),
+                });
+
+                let middle = sorted_sequence.len() / 2;
+                middle_nodes.push(sorted_sequence[middle]);
+            }
+        }
+
+        Some(middle_nodes.iter().sum())
+    }
+}
+
+pub fn parse(input: &str) -> <Day05 as Solution>::Input {
+    Day05::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day05::part_one(&Day05::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day05::part_two(&Day05::parse(input))
+}
+
+/// Keeps the rules that only mention pages of `sequence`.
+pub fn filter_rules(rules: &[(u32, u32)], sequence: &[u32]) -> Vec<(u32, u32)> {
+    rules
+        .iter()
+        .copied()
+        .filter(|(x, y)| sequence.contains(x) && sequence.contains(y))
+        .collect()
+}
+
+pub fn build_graph(rules: &[(u32, u32)]) -> (DiGraph<u32, ()>, HashMap<u32, NodeIndex>) {
+    let mut graph = DiGraph::<u32, ()>::new();
+    let mut node_map: HashMap<u32, NodeIndex> = HashMap::new();
+
+    for &(x, y) in rules {
+        let x_node = *node_map.entry(x).or_insert_with(|| graph.add_node(x));
+        let y_node = *node_map.entry(y).or_insert_with(|| graph.add_node(y));
+        graph.add_edge(x_node, y_node, ());
+    }
+
+    (graph, node_map)
+}
+
+pub fn validate_sequence(
+    graph: &DiGraph<u32, ()>,
+    sequence: &[u32],
+    node_map: &HashMap<u32, NodeIndex>,
+) -> bool {
+    // Create a HashMap lookup for the sequence indices
+    let seq_index: HashMap<u32, usize> =
+        sequence.iter().enumerate().map(|(i, &x)| (x, i)).collect();
+
+    // Iterate through each node in the sequence
+    for (i, &x) in sequence.iter().enumerate() {
+        if let Some(&x_node) = node_map.get(&x) {
+            // Compare against all following nodes in the sequence
+            for &y in &sequence[i + 1..] {
+                if let Some(&y_node) = node_map.get(&y) {
+                    // Check if a rule (edge) exists: X -> Y or Y -> X
+                    let x_to_y = graph.find_edge(x_node, y_node).is_some();
+                    let y_to_x = graph.find_edge(y_node, x_node).is_some();
+
+                    if (x_to_y && seq_index[&x] > seq_index[&y])
+                        || (y_to_x && seq_index[&y] > seq_index[&x])
+                    {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(143));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(123));
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use crate::days::Solution;
+use crate::grid::Grid;
+use crate::point::{Dir4, Point};
+use crate::template::visualize::{Color, Frame, Palette, Recorder};
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(6);
+
+pub struct Day06;
+
+/// Returns the map without the guard and the position of the guard.
+pub fn init_map(input: &str) -> (Grid<char>, Point) {
+    let mut map = Grid::parse(input, |c| c).expect("the map should be a grid");
+
+    // Find the guard position and replace it with an empty space
+    let guard = map
+        .find(|&c| c == '^')
+        .expect("the map should contain a guard");
+    map[guard] = '.';
+
+    (map, Point::from(guard))
+}
+
+fn palette() -> Palette {
+    Palette::new()
+        .with('#', Color::GRAY, "obstruction")
+        .with('O', Color::ORANGE, "new obstruction")
+        .with('X', Color::BLUE, "visited")
+        .with('^', Color::RED, "guard")
+        .with('>', Color::RED, "guard")
+        .with('v', Color::RED, "guard")
+        .with('<', Color::RED, "guard")
+}
+
+fn frame(
+    map: &Grid<char>,
+    positions: &HashSet<Point>,
+    position: Point,
+    direction: Dir4,
+    test_pos: Option<Point>,
+) -> Frame {
+    Frame::from_fn(map.width(), map.height(), |x, y| {
+        let pos = Point::from((x, y));
+        if pos == position {
+            direction.arrow()
+        } else if test_pos == Some(pos) {
+            'O'
+        } else if positions.contains(&pos) {
+            'X'
+        } else {
+            map[(x, y)]
+        }
+    })
+    .caption(format!("{} positions visited", positions.len()))
+}
+
+pub fn run_game(
+    map: &Grid<char>,
+    guard: Point,
+    test_pos: Option<Point>,
+    recorder: &mut Recorder,
+) -> (bool, HashSet<Point>) {
+    // Setup the game
+    let mut position = guard;
+    let mut direction = Dir4::North;
+    let mut positions = HashSet::new();
+    let mut state_history = HashSet::new();
+
+    positions.insert(position);
+    state_history.insert((position, direction));
+
+    // Run the game loop
+    loop {
+        let next_position = position + direction;
+
+        // Check if we will go out of bounds
+        let Some(&cell) = map.get(next_position) else {
+            recorder.key_frame(|| frame(map, &positions, position, direction, test_pos));
+            return (false, positions);
+        };
+
+        // Check if we hit an obstacle
+        let is_obstacle = cell == '#' || test_pos == Some(next_position);
+
+        if is_obstacle {
+            // Change direction
+            direction = direction.turn_right();
+        } else {
+            // Move to the next position
+            position = next_position;
+            positions.insert(position);
+        }
+
+        recorder.frame(|| frame(map, &positions, position, direction, test_pos));
+
+        // Save the position and direction to check for loops
+        if !state_history.insert((position, direction)) {
+            // We are in a loop
+            recorder.key_frame(|| frame(map, &positions, position, direction, test_pos));
+            return (true, positions);
+        }
+    }
+}
+
+impl Solution for Day06 {
+    type Input = (Grid<char>, Point);
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        init_map(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let (map, guard) = (&input.0, input.1);
+        let mut recorder = Recorder::new(DAY, "guard", palette());
+        let (_, positions) = run_game(map, guard, None, &mut recorder);
+        recorder.finish();
+        Some(positions.len() as u32)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let (map, guard) = (&input.0, input.1);
+        let (_, positions) = run_game(map, guard, None, &mut Recorder::disabled());
+        let mut loop_count = 0;
+
+        // Use the position history to find the path, and potential positions to place an additional obstacle
+        for position in positions {
+            let (is_loop, _) = run_game(map, guard, Some(position), &mut Recorder::disabled());
+            if is_loop {
+                loop_count += 1;
+            }
+        }
+
+        Some(loop_count)
+    }
+}
+
+pub fn parse(input: &str) -> <Day06 as Solution>::Input {
+    Day06::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day06::part_one(&Day06::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day06::part_two(&Day06::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(41));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(6));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::days::Solution;
+use crate::parse::ints;
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(7);
+
+pub struct Day07;
+
+#[derive(Clone, Copy)]
+pub enum Op {
+    Add,
+    Mul,
+    Concat,
+}
+
+pub fn evaluate(numbers: &[u64], ops: &[Op]) -> u64 {
+    let mut result = numbers[0];
+    for (i, &op) in ops.iter().enumerate() {
+        match op {
+            Op::Add => result += numbers[i + 1],
+            Op::Mul => result *= numbers[i + 1],
+            Op::Concat => {
+                let right = numbers[i + 1];
+                let mut digits = 0;
+                let mut temp = right;
+                while temp > 0 {
+                    digits += 1;
+                    temp /= 10;
+                }
+                result = result * 10_u64.pow(digits) + right;
+            }
+        }
+    }
+    result
+}
+
+/// Parse each line into the test value and the numbers of an equation
+pub fn parse_equations(input: &str) -> Vec<(u64, Vec<u64>)> {
+    input
+        .lines()
+        .map(|line| {
+            let mut numbers = ints::<u64>(line);
+            let answer = numbers.next().unwrap();
+            (answer, numbers.collect())
+        })
+        .collect()
+}
+
+impl Solution for Day07 {
+    type Input = Vec<(u64, Vec<u64>)>;
+    type PartOne = Option<u64>;
+    type PartTwo = Option<u64>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_equations(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let mut total = 0u64;
+
+        'outer: for &(answer, ref numbers) in input {
+            let n = numbers.len();
+            for mask in 0..(1 << (n - 1)) {
+                let mut result = numbers[0];
+
+                for (i, &number) in numbers.iter().enumerate().skip(1) {
+                    if mask & (1 << (i - 1)) != 0 {
+                        result += number;
+                    } else {
+                        result *= number;
+                    }
+                }
+
+                if result == answer {
+                    total += answer;
+                    continue 'outer; // Move to next line once we find any solution
+                }
+            }
+        }
+
+        Some(total)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let mut total = 0u64;
+
+        'outer: for &(answer, ref numbers) in input {
+            let n = numbers.len();
+            let ops = [Op::Add, Op::Mul, Op::Concat];
+
+            // Try all possible combinations of operators
+            for mask in 0..ops.len().pow((n - 1) as u32) {
+                let mut operators = Vec::new();
+                let mut temp = mask;
+
+                // Convert mask to operator sequence
+                for _ in 0..n - 1 {
+                    operators.push(ops[temp % ops.len()]);
+                    temp /= ops.len();
+                }
+
+                if evaluate(numbers, &operators) == answer {
+                    total += answer;
+                    continue 'outer;
+                }
+            }
+        }
+
+        Some(total)
+    }
+}
+
+pub fn parse(input: &str) -> <Day07 as Solution>::Input {
+    Day07::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    Day07::part_one(&Day07::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    Day07::part_two(&Day07::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(3749));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(11387));
+    }
+}
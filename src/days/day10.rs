@@ -0,0 +1,87 @@
+use crate::days::Solution;
+use crate::grid::Grid;
+use crate::search::Search;
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(10);
+
+pub struct Day10;
+
+/// Parse input into a grid of heights
+pub fn parse_grid(input: &str) -> Grid<u32> {
+    Grid::try_parse(input, |c| c.to_digit(10)).expect("the input should be a grid of digits")
+}
+
+/// Use breadth-first search to explore all possible paths that increment by exactly 1
+/// - every height-9 position is reached after exactly 9 steps, so all of them are goals on an
+///   optimal path, and every trail is one of the optimal paths
+pub fn find_paths(grid: &Grid<u32>) -> (u32, u32) {
+    // Part 1: Sum of unique height-9 positions reachable
+    let mut total_endpoints = 0;
+    // Part 2: Sum of unique valid paths to height 9
+    let mut total_complete_paths = 0;
+
+    // Iterate through grid to find all trailheads (height 0)
+    for trailhead in grid.find_all(|&height| height == 0) {
+        let search = Search::new([trailhead]).all_paths().bfs(
+            |&pos| {
+                grid.neighbours_4(pos)
+                    .filter(move |&next| grid[next] == grid[pos] + 1)
+            },
+            |&pos| grid[pos] == 9,
+        );
+
+        // Add this trailhead's results to totals
+        total_endpoints += search.goals().count() as u64;
+        total_complete_paths += search.count_optimal_paths();
+    }
+
+    (total_endpoints as u32, total_complete_paths as u32)
+}
+
+impl Solution for Day10 {
+    type Input = Grid<u32>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_grid(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        Some(find_paths(input).0)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        Some(find_paths(input).1)
+    }
+}
+
+pub fn parse(input: &str) -> <Day10 as Solution>::Input {
+    Day10::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day10::part_one(&Day10::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day10::part_two(&Day10::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(36));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(81));
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use crate::days::Solution;
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(11);
+
+pub struct Day11;
+
+/// Transforms a stone according to rules:
+/// - 0 becomes 1
+/// - Even number of digits splits into two stones
+/// - Otherwise multiply by 2024
+pub fn process_stone(num: u64) -> Vec<u64> {
+    if num == 0 {
+        return vec![1];
+    }
+
+    // Get number of digits using log10
+    let digits = (num as f64).log10().floor() as u32 + 1;
+    if digits.is_multiple_of(2) {
+        let half_digits = digits / 2;
+        let divisor = 10_u64.pow(half_digits);
+        vec![num / divisor, num % divisor]
+    } else {
+        vec![num * 2024]
+    }
+}
+
+/// Recursively counts stones after given iterations
+/// Uses memoization to avoid recalculating same values
+pub fn count_stones(num: u64, iterations: u32, cache: &mut HashMap<(u64, u32), u64>) -> u64 {
+    match (iterations, cache.get(&(num, iterations))) {
+        (0, _) => 1,
+        (_, Some(&count)) => count,
+        (_, None) => {
+            let count = process_stone(num)
+                .into_iter()
+                .map(|n| count_stones(n, iterations - 1, cache))
+                .sum();
+            cache.insert((num, iterations), count);
+            count
+        }
+    }
+}
+
+pub fn parse_stones(input: &str) -> Vec<u64> {
+    input
+        .split_whitespace()
+        .map(|s| s.parse::<u64>().unwrap())
+        .collect()
+}
+
+/// Solves both parts using the same logic with different iteration counts
+pub fn solve(stones: &[u64], iterations: u32) -> u64 {
+    stones
+        .iter()
+        .map(|&n| count_stones(n, iterations, &mut HashMap::new()))
+        .sum()
+}
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type PartOne = Option<u64>;
+    type PartTwo = Option<u64>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_stones(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        Some(solve(input, 25))
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        Some(solve(input, 75))
+    }
+}
+
+pub fn parse(input: &str) -> <Day11 as Solution>::Input {
+    Day11::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    Day11::part_one(&Day11::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    Day11::part_two(&Day11::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(55312));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(65601038650482));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::days::Solution;
+use crate::grid::Grid;
+use crate::point::{Dir4, Dir8, Point};
+use crate::search::Search;
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(12);
+
+pub struct Day12;
+
+/// Parse input into a grid of characters
+pub fn parse_grid(input: &str) -> Grid<char> {
+    Grid::parse(input, |c| c).expect("the input should be a grid of plants")
+}
+
+// Returns (area, perimeter, corners) for each region
+pub fn solve(grid: &Grid<char>) -> Vec<(u32, u32, u32)> {
+    // Initialize sets to keep track of visited cells and results
+    let mut visited = Grid::new(grid.width(), grid.height(), false);
+    let mut results = Vec::new();
+
+    // Helper function to check if a cell is within bounds and has the target character
+    let is_target = |pos: Point, target: char| grid.get(pos) == Some(&target);
+
+    // Iterate through the grid
+    for start in grid.positions() {
+        // If the cell hasn't been visited yet
+        if !visited[start] {
+            // Standard BFS flood fill
+            let target = grid[start];
+            let region: Vec<(usize, usize)> = Search::new([start])
+                .bfs(
+                    |&pos| {
+                        grid.neighbours_4(pos)
+                            .filter(move |&next| grid[next] == target)
+                    },
+                    |_| false,
+                )
+                .reached()
+                .map(|(&pos, _)| pos)
+                .collect();
+            for &pos in &region {
+                visited[pos] = true;
+            }
+
+            // Initialize area, perimeter, and corners
+            let area = region.len() as u32;
+            let mut perimeter = 0;
+            let mut corners = 0;
+
+            // Iterate through the region
+            for &pos in &region {
+                let pos = Point::from(pos);
+
+                // Count perimeters by checking adjacent cardinal cells
+                for dir in Dir4::ALL {
+                    if !is_target(pos + dir, target) {
+                        perimeter += 1;
+                    }
+                }
+
+                // Count the corners between each cardinal direction and the next one clockwise,
+                // whose diagonal lies in between
+                for dir in Dir4::ALL {
+                    let side = is_target(pos + dir, target);
+                    let next = is_target(pos + dir.turn_right(), target);
+                    let diagonal = is_target(pos + Dir8::from(dir).turn_right(), target);
+
+                    // Outer corner
+                    if !side && !next {
+                        corners += 1;
+                    }
+                    // Inner corner
+                    if side && next && !diagonal {
+                        corners += 1;
+                    }
+                }
+            }
+
+            results.push((area, perimeter, corners));
+        }
+    }
+
+    results
+}
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_grid(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        Some(
+            solve(input)
+                .into_iter()
+                .map(|(area, perimeter, _)| area * perimeter)
+                .sum(),
+        )
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        Some(
+            solve(input)
+                .into_iter()
+                .map(|(area, _, corners)| area * corners)
+                .sum(),
+        )
+    }
+}
+
+pub fn parse(input: &str) -> <Day12 as Solution>::Input {
+    Day12::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day12::part_one(&Day12::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day12::part_two(&Day12::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(1930));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(1206));
+    }
+}
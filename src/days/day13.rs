@@ -0,0 +1,291 @@
+use std::cmp::min;
+
+use crate::template::fuzz::{Fuzzer, Oracle, Rng};
+use crate::template::variants::Variant;
+
+use crate::days::Solution;
+use crate::linear::min_cost_integer_solution;
+use crate::math::gcd;
+use crate::parse::{sections, Pattern};
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(13);
+
+pub struct Day13;
+
+/// Compares Cramer's rule against the brute force on part one sized machines.
+pub const FUZZER: Fuzzer<i64> = Fuzzer {
+    generate: generate_machines,
+    oracles: &[Oracle {
+        name: "cramer",
+        candidate: part_one_cramer,
+        reference: part_one,
+    }],
+};
+
+/// The brute force only covers part one, part two needs far more than 100 presses.
+pub const NAIVE: Variant<Option<i64>> = Variant {
+    name: "naive",
+    part_one: Some(part_one),
+    part_two: None,
+};
+
+pub const CRAMER: Variant<Option<i64>> = Variant {
+    name: "cramer",
+    part_one: Some(part_one_cramer),
+    part_two: Some(part_two),
+};
+
+/// struct to hold Machine info
+#[derive(Clone, Copy)]
+pub struct Machine {
+    pub a_x: i64,
+    pub a_y: i64,
+    pub a_cost: i64,
+    pub b_x: i64,
+    pub b_y: i64,
+    pub b_cost: i64,
+    pub target_x: i64,
+    pub target_y: i64,
+}
+
+/// Parse input and initialize Machines
+pub fn init(input: &str, offset: i64) -> Vec<Machine> {
+    let button_a = Pattern::new("Button A: X+{}, Y+{}");
+    let button_b = Pattern::new("Button B: X+{}, Y+{}");
+    let prize = Pattern::new("Prize: X={}, Y={}");
+
+    sections(input)
+        .map(|section| {
+            let lines: Vec<&str> = section.lines().collect();
+            let (a_x, a_y) = button_a.parse(lines[0]).unwrap();
+            let (b_x, b_y) = button_b.parse(lines[1]).unwrap();
+            let (target_x, target_y): (i64, i64) = prize.parse(lines[2]).unwrap();
+
+            Machine {
+                a_x,
+                a_y,
+                a_cost: 3,
+                b_x,
+                b_y,
+                b_cost: 1,
+                target_x: target_x + offset,
+                target_y: target_y + offset,
+            }
+        })
+        .collect()
+}
+
+/// Part 1: Solve machine using brute force
+pub fn solve_machine(machine: &Machine) -> Option<i64> {
+    let gcd_x = gcd(machine.a_x, machine.b_x);
+    let gcd_y = gcd(machine.a_y, machine.b_y);
+
+    // Check if prize positions are reachable
+    if machine.target_x % gcd_x != 0 || machine.target_y % gcd_y != 0 {
+        return None;
+    }
+
+    let mut min_cost = None;
+
+    // Try different combinations up to 100 presses
+    for a in 0..=100 {
+        for b in 0..=100 {
+            let x = a * machine.a_x + b * machine.b_x;
+            let y = a * machine.a_y + b * machine.b_y;
+
+            if x == machine.target_x && y == machine.target_y {
+                let cost = a * machine.a_cost + b * machine.b_cost;
+                min_cost = Some(match min_cost {
+                    Some(current_min) => min(current_min, cost),
+                    None => cost,
+                });
+            }
+        }
+    }
+
+    min_cost
+}
+
+/// Part 2: Solve machine using Cramer's rule, optionally limiting the presses per button. Falls
+/// back to the exact solver when the buttons are collinear.
+pub fn solve_machine_cramer(machine: &Machine, max_presses: Option<i64>) -> Option<i64> {
+    // Calculate determinant
+    let det = machine.a_x * machine.b_y - machine.a_y * machine.b_x;
+    if det == 0 {
+        let (_, tokens) = min_cost_integer_solution(
+            &[
+                [machine.a_x.into(), machine.b_x.into()],
+                [machine.a_y.into(), machine.b_y.into()],
+            ],
+            &[machine.target_x.into(), machine.target_y.into()],
+            [machine.a_cost.into(), machine.b_cost.into()],
+            max_presses.map(i128::from),
+        )?;
+        return tokens.try_into().ok();
+    }
+
+    // Calculate numerators for A and B using Cramer's rule
+    let num_a = machine.target_x * machine.b_y - machine.target_y * machine.b_x;
+    let num_b = machine.a_x * machine.target_y - machine.a_y * machine.target_x;
+
+    // Check if we have integer solutions
+    if num_a % det != 0 || num_b % det != 0 {
+        return None;
+    }
+
+    // Calculate A and B
+    let a = num_a / det;
+    let b = num_b / det;
+
+    // Check if solution is non-negative and within the limit
+    if a >= 0 && b >= 0 && max_presses.is_none_or(|max| a <= max && b <= max) {
+        // Verify solution
+        let x = a * machine.a_x + b * machine.b_x;
+        let y = a * machine.a_y + b * machine.b_y;
+        if x == machine.target_x && y == machine.target_y {
+            return Some(a * machine.a_cost + b * machine.b_cost);
+        }
+    }
+
+    None
+}
+
+/// Generate `size` machines with button offsets in the range used by real inputs.
+/// Most prizes are reachable within 100 presses, the rest are placed at random. Some machines get
+/// collinear buttons, which random offsets hardly ever produce.
+pub fn generate_machines(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (ax, ay) = (rng.range(10, 99), rng.range(10, 99));
+            let (bx, by) = if rng.one_in(8) {
+                // a multiple of the smallest step in the direction of A, `gcd(ax, ay)` gives A.
+                let g = gcd(ax, ay);
+                let (ux, uy) = (ax / g, ay / g);
+                let k = rng.range((9 + ux.min(uy)) / ux.min(uy), 99 / ux.max(uy));
+                (k * ux, k * uy)
+            } else {
+                (rng.range(10, 99), rng.range(10, 99))
+            };
+            let (px, py) = if rng.one_in(4) {
+                (rng.range(0, 20_000), rng.range(0, 20_000))
+            } else {
+                let (a, b) = (rng.range(0, 100), rng.range(0, 100));
+                (a * ax + b * bx, a * ay + b * by)
+            };
+            format!("Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={px}, Y={py}\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part_one_cramer(input: &str) -> Option<i64> {
+    Some(
+        init(input, 0)
+            .iter()
+            .filter_map(|machine| solve_machine_cramer(machine, Some(100)))
+            .sum(),
+    )
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    type PartOne = Option<i64>;
+    type PartTwo = Option<i64>;
+
+    fn parse(input: &str) -> Self::Input {
+        init(input, 0)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let machines = input;
+        let mut total_tokens = 0;
+
+        for machine in machines.iter() {
+            if let Some(tokens) = solve_machine(machine) {
+                total_tokens += tokens;
+            }
+        }
+
+        Some(total_tokens)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let machines = input.iter().map(|machine| Machine {
+            target_x: machine.target_x + 10_000_000_000_000,
+            target_y: machine.target_y + 10_000_000_000_000,
+            ..*machine
+        });
+        let mut total_tokens = 0;
+
+        for machine in machines {
+            if let Some(tokens) = solve_machine_cramer(&machine, None) {
+                total_tokens += tokens;
+            }
+        }
+
+        Some(total_tokens)
+    }
+}
+
+pub fn parse(input: &str) -> <Day13 as Solution>::Input {
+    Day13::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    Day13::part_one(&Day13::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    Day13::part_two(&Day13::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(480));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(875318608908));
+    }
+
+    #[test]
+    fn test_generate_machines() {
+        let machines = init(&generate_machines(&mut Rng::new(1), 200), 0);
+        assert!(machines.iter().all(|m| [m.a_x, m.a_y, m.b_x, m.b_y]
+            .iter()
+            .all(|offset| (10..=99).contains(offset))));
+        assert!(machines.iter().any(|m| m.a_x * m.b_y == m.a_y * m.b_x));
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        let machine = init(
+            "Button A: X+2, Y+4\nButton B: X+3, Y+6\nPrize: X=12, Y=24\n",
+            0,
+        )
+        .remove(0);
+        assert_eq!(solve_machine(&machine), Some(4));
+        assert_eq!(solve_machine_cramer(&machine, None), Some(4));
+    }
+
+    #[test]
+    fn test_collinear_buttons_press_limit() {
+        // the cheapest prize takes 103 presses of A, more than part one allows
+        let machine = init(
+            "Button A: X+47, Y+47\nButton B: X+14, Y+14\nPrize: X=5065, Y=5065\n",
+            0,
+        )
+        .remove(0);
+        assert_eq!(solve_machine(&machine), Some(330));
+        assert_eq!(solve_machine_cramer(&machine, Some(100)), Some(330));
+        assert_eq!(solve_machine_cramer(&machine, None), Some(325));
+    }
+}
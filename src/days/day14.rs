@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use crate::days::Solution;
+use crate::math::crt;
+use crate::parse::Pattern;
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(14);
+
+pub struct Day14;
+
+pub struct Robot {
+    pub position: (isize, isize),
+    pub velocity: (isize, isize),
+}
+
+pub fn parse_robots(input: &str) -> Vec<Robot> {
+    let robot = Pattern::new("p={},{} v={},{}");
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (px, py, vx, vy) = robot.parse(line).unwrap();
+            Robot {
+                position: (px, py),
+                velocity: (vx, vy),
+            }
+        })
+        .collect()
+}
+
+// Function to compute new position with wrapping
+pub fn compute_position(start: isize, velocity: isize, time: isize, bound: isize) -> usize {
+    (start + velocity * time).rem_euclid(bound) as usize
+}
+
+pub fn calculate_safety_factor(
+    robots: &[Robot],
+    width: isize,
+    height: isize,
+    time: isize,
+) -> usize {
+    let mut quadrants: HashMap<&str, usize> = HashMap::new();
+    quadrants.insert("top-left", 0);
+    quadrants.insert("top-right", 0);
+    quadrants.insert("bottom-left", 0);
+    quadrants.insert("bottom-right", 0);
+
+    let mid_x = width / 2;
+    let mid_y = height / 2;
+
+    for robot in robots {
+        let x_new = compute_position(robot.position.0, robot.velocity.0, time, width);
+        let y_new = compute_position(robot.position.1, robot.velocity.1, time, height);
+
+        // Skip robots exactly on the middle lines
+        if x_new as isize == mid_x || y_new as isize == mid_y {
+            continue;
+        }
+
+        let quadrant = match (x_new as isize <= mid_x, y_new as isize <= mid_y) {
+            (true, true) => "top-left",
+            (false, true) => "top-right",
+            (true, false) => "bottom-left",
+            (false, false) => "bottom-right",
+        };
+
+        *quadrants.get_mut(quadrant).unwrap() += 1;
+    }
+
+    // Return the product of all quadrant counts
+    quadrants.values().product()
+}
+
+pub fn variance(numbers: Vec<usize>) -> f64 {
+    let n = numbers.len() as f64;
+    let mean = numbers.iter().sum::<usize>() as f64 / n;
+    let variance = numbers
+        .iter()
+        .map(|&x| {
+            let diff = x as f64 - mean;
+            diff * diff
+        })
+        .sum::<f64>()
+        / n;
+    variance
+}
+
+/// Draws the region of the grid containing robots at `time`.
+pub fn render_positions(robots: &[Robot], time: isize, width: isize, height: isize) -> String {
+    let positions: Vec<(usize, usize)> = robots
+        .iter()
+        .map(|r| {
+            (
+                compute_position(r.position.0, r.velocity.0, time, width),
+                compute_position(r.position.1, r.velocity.1, time, height),
+            )
+        })
+        .collect();
+
+    // Find bounds of actual message
+    let min_x = positions.iter().map(|&(x, _)| x).min().unwrap_or(0);
+    let max_x = positions
+        .iter()
+        .map(|&(x, _)| x)
+        .max()
+        .unwrap_or(width as usize);
+    let min_y = positions.iter().map(|&(_, y)| y).min().unwrap_or(0);
+    let max_y = positions
+        .iter()
+        .map(|&(_, y)| y)
+        .max()
+        .unwrap_or(height as usize);
+
+    // Draw only the region containing the message
+    let mut s = String::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            if positions.iter().any(|&(px, py)| px == x && py == y) {
+                s.push('🤖');
+            } else {
+                s.push('🟩');
+            }
+        }
+        s.push('\n');
+    }
+    s
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_robots(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let safety_factor = calculate_safety_factor(input, 101, 103, 100);
+        Some(safety_factor as u32)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let robots = input;
+        let width = 101;
+        let height = 103;
+
+        // The x positions repeat every `width` steps and the y positions every `height` steps, so
+        // the time with the least variance on each axis can be found separately and combined
+        let best_x = least_variance_time(width, |t| {
+            robots
+                .iter()
+                .map(|r| compute_position(r.position.0, r.velocity.0, t, width))
+                .collect()
+        });
+        let best_y = least_variance_time(height, |t| {
+            robots
+                .iter()
+                .map(|r| compute_position(r.position.1, r.velocity.1, t, height))
+                .collect()
+        });
+        let (best_time, _) = crt([(best_x, width), (best_y, height)])?;
+
+        crate::aoc_debug!(
+            "Message at time {best_time}:\n{}",
+            render_positions(robots, best_time, width, height)
+        );
+        Some(best_time as u32)
+    }
+}
+
+/// Returns the time in `0..period` at which the coordinates returned by `coordinates` have the
+/// least variance.
+fn least_variance_time(period: isize, coordinates: impl Fn(isize) -> Vec<usize>) -> isize {
+    let mut best_time = 0;
+    let mut min_variance = f64::MAX;
+
+    for t in 0..period {
+        let variance = variance(coordinates(t));
+        if variance < min_variance {
+            crate::aoc_trace!("t={t} (mod {period}): variance {variance:.1}");
+            min_variance = variance;
+            best_time = t;
+        }
+    }
+
+    best_time
+}
+
+pub fn parse(input: &str) -> <Day14 as Solution>::Input {
+    Day14::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day14::part_one(&Day14::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day14::part_two(&Day14::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::log::{capture, Level};
+
+    #[test]
+    fn test_part_one() {
+        let robots = parse_robots(&crate::template::read_file("examples", DAY));
+        let result = calculate_safety_factor(&robots, 11, 7, 100);
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::template::read_file("examples", DAY);
+        let (result, log) = capture(Level::Debug, || part_two(&input));
+        assert_eq!(result, Some(5253));
+        assert_eq!(log.len(), 1);
+        assert!(log[0].starts_with("Message at time 5253:\n"));
+    }
+}
@@ -0,0 +1,300 @@
+use crate::days::Solution;
+use crate::grid::Grid;
+use crate::point::Dir4;
+use crate::template::visualize::{Color, Frame, Palette, Recorder};
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(15);
+
+pub struct Day15;
+
+/// Returns the warehouse map and the robot's moves.
+pub fn parse_map(input: &str) -> (Grid<char>, String) {
+    // The map is separated from the moves by a blank line
+    let mut lines = input.lines();
+    let map = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let map = Grid::parse(&map, |c| c).expect("the map should be a grid");
+
+    let moves_str: String = lines.collect();
+    (map, moves_str)
+}
+
+// Find robot position
+pub fn find_robot(warehouse: &Grid<char>) -> (usize, usize) {
+    warehouse.find(|&c| c == '@').expect("Robot not found")
+}
+
+fn palette() -> Palette {
+    Palette::new()
+        .with('#', Color::GRAY, "wall")
+        .with('O', Color::YELLOW, "box")
+        .with('[', Color::YELLOW, "box")
+        .with(']', Color::YELLOW, "box")
+        .with('@', Color::RED, "robot")
+}
+
+fn frame(warehouse: &Grid<char>, moves: usize) -> Frame {
+    Frame::from_fn(warehouse.width(), warehouse.height(), |x, y| {
+        warehouse[(x, y)]
+    })
+    .caption(format!("{moves} moves"))
+}
+
+// Check if we can push a chain of boxes
+pub fn can_push(warehouse: &Grid<char>, pos: (usize, usize), dir: Dir4) -> bool {
+    let Some(next) = warehouse.offset(pos, dir.into()) else {
+        return false;
+    };
+
+    match warehouse[next] {
+        '.' => true,
+        'O' | '[' | ']' => can_push(warehouse, next, dir),
+        _ => false,
+    }
+}
+
+/// Moves the robot from `robot` to `next` and returns its new position.
+fn move_robot(
+    warehouse: &mut Grid<char>,
+    robot: (usize, usize),
+    next: (usize, usize),
+) -> (usize, usize) {
+    warehouse[robot] = '.';
+    warehouse[next] = '@';
+    next
+}
+
+impl Solution for Day15 {
+    type Input = (Grid<char>, String);
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_map(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let (map, moves_str) = input;
+
+        // Create the warehouse
+        let mut warehouse = map.clone();
+
+        // Find robot position
+        let mut robot = find_robot(&warehouse);
+
+        // Actually push the boxes
+        fn push_boxes(warehouse: &mut Grid<char>, pos: (usize, usize), dir: Dir4) {
+            let next = warehouse.offset(pos, dir.into()).unwrap();
+            if warehouse[next] == 'O' {
+                push_boxes(warehouse, next, dir);
+            }
+            warehouse[next] = 'O';
+            warehouse[pos] = '.';
+        }
+
+        // Attempt a move
+        fn attempt_move(
+            warehouse: &mut Grid<char>,
+            robot: (usize, usize),
+            dir: Dir4,
+        ) -> (usize, usize) {
+            let Some(next) = warehouse.offset(robot, dir.into()) else {
+                return robot;
+            };
+            match warehouse[next] {
+                // Move robot
+                '.' => move_robot(warehouse, robot, next),
+                // Need to push, do nothing if we cannot
+                'O' if can_push(warehouse, next, dir) => {
+                    push_boxes(warehouse, next, dir);
+                    // Now place robot
+                    move_robot(warehouse, robot, next)
+                }
+                // Move blocked, do nothing
+                _ => robot,
+            }
+        }
+
+        // Process all moves
+        let mut recorder = Recorder::new(DAY, "warehouse", palette());
+        recorder.key_frame(|| frame(&warehouse, 0));
+        for (i, ch) in moves_str.chars().enumerate() {
+            robot = attempt_move(&mut warehouse, robot, Dir4::try_from(ch).unwrap());
+            recorder.frame(|| frame(&warehouse, i + 1));
+        }
+        recorder.key_frame(|| frame(&warehouse, moves_str.len()));
+        recorder.finish();
+
+        // Compute sum of GPS coordinates
+        let sum: usize = warehouse
+            .find_all(|&c| c == 'O')
+            .map(|(x, y)| 100 * y + x)
+            .sum();
+
+        Some(sum as u32)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let (map, moves_str) = input;
+
+        // Create the warehouse
+        let mut warehouse = Grid::from_fn(map.width() * 2, map.height(), |(x, y)| {
+            match (map[(x / 2, y)], x % 2) {
+                ('#', _) => '#',
+                ('O', 0) => '[',
+                ('O', _) => ']',
+                ('@', 0) => '@',
+                _ => '.',
+            }
+        });
+
+        // Find robot
+        let mut robot = find_robot(&warehouse);
+
+        fn find_connected_boxes(
+            warehouse: &Grid<char>,
+            pos: (usize, usize),
+            dir: Dir4,
+            visited: &mut Grid<bool>,
+        ) -> Vec<(usize, usize)> {
+            let mut result = vec![pos];
+            visited[pos] = true;
+
+            if dir.is_horizontal() {
+                // Moving horizontally - only check left/right
+                for side in [Dir4::East, Dir4::West] {
+                    if let Some(next) = warehouse.offset(pos, side.into()) {
+                        if !visited[next] && matches!(warehouse[next], '[' | ']') {
+                            let mut connected = find_connected_boxes(warehouse, next, dir, visited);
+                            result.append(&mut connected);
+                        }
+                    }
+                }
+            } else {
+                // Moving vertically - need to handle double-width boxes
+                // First find the left bracket of the current box pair
+                let (x, y) = pos;
+                let start_x = if warehouse[pos] == '[' { x } else { x - 1 };
+
+                // Mark both parts of the current box as visited
+                for part in [(start_x, y), (start_x + 1, y)] {
+                    if !visited[part] {
+                        visited[part] = true;
+                        result.push(part);
+                    }
+                }
+
+                // Check both positions in the next row that could be touching this box
+                for part in [(start_x, y), (start_x + 1, y)] {
+                    let Some(next) = warehouse.offset(part, dir.into()) else {
+                        continue;
+                    };
+                    if !visited[next] && matches!(warehouse[next], '[' | ']') {
+                        let mut connected = find_connected_boxes(warehouse, next, dir, visited);
+                        result.append(&mut connected);
+                    }
+                }
+            }
+
+            result
+        }
+
+        fn attempt_move(
+            warehouse: &mut Grid<char>,
+            robot: (usize, usize),
+            dir: Dir4,
+        ) -> (usize, usize) {
+            let Some(next) = warehouse.offset(robot, dir.into()) else {
+                return robot;
+            };
+            match warehouse[next] {
+                '.' => move_robot(warehouse, robot, next),
+                '[' | ']' => {
+                    // Find all connected boxes
+                    let mut visited = Grid::new(warehouse.width(), warehouse.height(), false);
+                    let connected = find_connected_boxes(warehouse, next, dir, &mut visited);
+
+                    // Check if all boxes can be moved
+                    let can_move = connected.iter().all(|&pos| can_push(warehouse, pos, dir));
+                    if !can_move {
+                        return robot;
+                    }
+
+                    // Store the box configuration
+                    let boxes: Vec<(char, (usize, usize))> =
+                        connected.iter().map(|&pos| (warehouse[pos], pos)).collect();
+
+                    // Clear original positions
+                    for (_, pos) in &boxes {
+                        warehouse[*pos] = '.';
+                    }
+
+                    // Place boxes in new positions
+                    for (ch, pos) in boxes {
+                        let new_pos = warehouse.offset(pos, dir.into()).unwrap();
+                        warehouse[new_pos] = ch;
+                    }
+
+                    // Move robot
+                    move_robot(warehouse, robot, next)
+                }
+                _ => robot,
+            }
+        }
+
+        // Process moves
+        let mut recorder = Recorder::new(DAY, "wide-warehouse", palette());
+        recorder.key_frame(|| frame(&warehouse, 0));
+        for (i, ch) in moves_str.chars().enumerate() {
+            robot = attempt_move(&mut warehouse, robot, Dir4::try_from(ch).unwrap());
+            recorder.frame(|| frame(&warehouse, i + 1));
+        }
+        recorder.key_frame(|| frame(&warehouse, moves_str.len()));
+        recorder.finish();
+
+        // Compute sum of GPS coordinates for all boxes
+        let sum: usize = warehouse
+            .find_all(|&c| c == '[')
+            .map(|(x, y)| 100 * y + x)
+            .sum();
+
+        Some(sum as u32)
+    }
+}
+
+pub fn parse(input: &str) -> <Day15 as Solution>::Input {
+    Day15::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day15::part_one(&Day15::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day15::part_two(&Day15::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(10092));
+        let result_small = part_one(&crate::template::read_file_part("examples", DAY, 2));
+        assert_eq!(result_small, Some(2028));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(9021));
+        let result_small = part_two(&crate::template::read_file_part("examples", DAY, 2));
+        assert_eq!(result_small, Some(1751));
+    }
+}
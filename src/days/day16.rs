@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use crate::days::Solution;
+use crate::grid::Grid;
+use crate::point::Dir4;
+use crate::search::{Search, SearchResult};
+use crate::template::visualize::{Color, Frame, Palette, Recorder};
+use crate::template::Day;
+
+pub const DAY: Day = crate::day!(16);
+
+pub struct Day16;
+
+pub const TURN_COST: u32 = 1000;
+
+pub struct Maze {
+    pub grid: Grid<char>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+impl Maze {
+    pub fn new(input: &str) -> Self {
+        let grid = Grid::parse(input, |c| c).expect("the maze should be a grid");
+        let start = grid
+            .find(|&c| c == 'S')
+            .expect("the maze should have a start");
+        let end = grid
+            .find(|&c| c == 'E')
+            .expect("the maze should have an end");
+
+        Self { grid, start, end }
+    }
+}
+
+/// A tile of the maze and the direction the reindeer faces on it.
+pub type Reindeer = ((usize, usize), Dir4);
+
+/// Searches the cheapest paths from the start, facing east, to the end.
+pub fn run_maze(maze: &Maze) -> SearchResult<Reindeer, u32> {
+    Search::new([(maze.start, Dir4::East)])
+        .all_paths()
+        .dijkstra(
+            |&(pos, dir)| {
+                // Move forward, or turn left/right
+                let forward = maze
+                    .grid
+                    .offset(pos, dir.into())
+                    .filter(|&next| maze.grid[next] != '#')
+                    .map(|next| ((next, dir), 1));
+                forward.into_iter().chain([
+                    ((pos, dir.turn_left()), TURN_COST),
+                    ((pos, dir.turn_right()), TURN_COST),
+                ])
+            },
+            |&(pos, _)| pos == maze.end,
+        )
+}
+
+/// Returns the tiles on any of the best paths.
+pub fn best_path_tiles(search: &SearchResult<Reindeer, u32>) -> HashSet<(usize, usize)> {
+    search
+        .on_optimal_paths()
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn palette() -> Palette {
+    Palette::new()
+        .with('#', Color::GRAY, "wall")
+        .with('~', Color::BLUE, "explored")
+        .with('O', Color::YELLOW, "best path")
+        .with('S', Color::GREEN, "start")
+        .with('E', Color::RED, "end")
+}
+
+/// Records the tiles reached within increasing costs, followed by the tiles on best paths.
+fn record_search(
+    maze: &Maze,
+    search: &SearchResult<Reindeer, u32>,
+    best_path: &HashSet<(usize, usize)>,
+) {
+    let mut recorder = Recorder::new(DAY, "maze", palette());
+    let Some(best_score) = search.cost().filter(|_| recorder.is_active()) else {
+        return;
+    };
+
+    let reached = |pos: (usize, usize), within: u32| {
+        Dir4::ALL
+            .into_iter()
+            .any(|dir| search.distance(&(pos, dir)).is_some_and(|d| d <= within))
+    };
+    let tile = |pos: (usize, usize), within: u32| match maze.grid[pos] {
+        'S' | 'E' | '#' => maze.grid[pos],
+        _ if reached(pos, within) => '~',
+        _ => '.',
+    };
+    let (width, height) = (maze.grid.width(), maze.grid.height());
+
+    for within in (0..=best_score).step_by((best_score / 200).max(1) as usize) {
+        recorder.frame(|| {
+            Frame::from_fn(width, height, |x, y| tile((x, y), within))
+                .caption(format!("cost {within}"))
+        });
+    }
+    recorder.key_frame(|| {
+        Frame::from_fn(width, height, |x, y| match maze.grid[(x, y)] {
+            'S' | 'E' => maze.grid[(x, y)],
+            _ if best_path.contains(&(x, y)) => 'O',
+            _ => tile((x, y), best_score),
+        })
+        .caption(format!("best score {best_score}"))
+    });
+    recorder.finish();
+}
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    fn parse(input: &str) -> Self::Input {
+        Maze::new(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        run_maze(input).cost()
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let search = run_maze(input);
+        let best_path = best_path_tiles(&search);
+        record_search(input, &search, &best_path);
+        // Return the number of tiles on the best path
+        Some(best_path.len() as u32)
+    }
+}
+
+pub fn parse(input: &str) -> <Day16 as Solution>::Input {
+    Day16::parse(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Day16::part_one(&Day16::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Day16::part_two(&Day16::parse(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(7036));
+        let result_two = part_one(&crate::template::read_file_part("examples", DAY, 2));
+        assert_eq!(result_two, Some(11048));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(45));
+        let result_two = part_two(&crate::template::read_file_part("examples", DAY, 2));
+        assert_eq!(result_two, Some(64));
+    }
+}
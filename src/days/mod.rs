@@ -0,0 +1,36 @@
+/// The solutions of every day as library modules.
+///
+/// Each `dayNN` module implements [`Solution`] and exports `parse`, `part_one` and `part_two`
+/// functions that take the raw input, which the binary in `src/bin/NN.rs` passes to the `solution!`
+/// macro. Parsers
+/// and helper types are public, so other days, benchmarks and tools can reuse them.
+use crate::template::runner::PartResult;
+
+/// A puzzle solution whose parts share one parsed input.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input;
+    type PartOne: PartResult;
+    type PartTwo: PartResult;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
@@ -0,0 +1,483 @@
+/// A two-dimensional grid of cells, as found in most puzzle inputs.
+///
+/// Cells are stored row by row in a flat `Vec`. Positions are `(x, y)` pairs, where `x` is the
+/// column and `y` the row, both counted from the top left. Methods taking a position accept any
+/// [`Coordinate`], so signed positions that may lie outside of the grid can be looked up without
+/// casting or bounds checks: [`Grid::get`] simply returns `None` for them.
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::point::{Dir4, Dir8};
+
+/// A position that may or may not lie within a grid.
+pub trait Coordinate: Copy {
+    /// Returns the position as unsigned `(x, y)`, or `None` if a component is negative.
+    fn xy(self) -> Option<(usize, usize)>;
+}
+
+impl Coordinate for (usize, usize) {
+    fn xy(self) -> Option<(usize, usize)> {
+        Some(self)
+    }
+}
+
+macro_rules! impl_signed_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for ($t, $t) {
+                fn xy(self) -> Option<(usize, usize)> {
+                    Some((usize::try_from(self.0).ok()?, usize::try_from(self.1).ok()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_coordinate!(isize, i32, i64);
+
+/// Like [`GridError::Empty`] when parsing, a grid always has at least one cell, so rows and columns
+/// are never empty.
+fn assert_not_empty(width: usize, height: usize) {
+    assert!(
+        width > 0 && height > 0,
+        "a grid needs at least one cell, got {width}x{height}"
+    );
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`. Panics unless both dimensions are positive.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert_not_empty(width, height);
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid by calling `f` with the `(x, y)` position of every cell. Panics unless both
+    /// dimensions are positive.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        assert_not_empty(width, height);
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line, mapping every character to a cell with `f`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Self::try_parse(input, |c| Some(f(c)))
+    }
+
+    /// Parses one row per line, mapping every character to a cell with `f`. Characters for which
+    /// `f` returns `None` are reported as invalid.
+    pub fn try_parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let len = line.chars().count();
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(GridError::Ragged {
+                        line: y + 1,
+                        expected: width,
+                        found: len,
+                    })
+                }
+                Some(_) => {}
+            }
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(c).ok_or(GridError::InvalidCell { x, y, cell: c })?);
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                cells,
+                width,
+                height,
+            }),
+            _ => Err(GridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `pos` lies within the grid.
+    pub fn contains(&self, pos: impl Coordinate) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    fn index_of(&self, pos: impl Coordinate) -> Option<usize> {
+        let (x, y) = pos.xy()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, pos: impl Coordinate) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: impl Coordinate) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns the position `(dx, dy)` away from `pos`, if it lies within the grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Iterates over the positions of the up to four orthogonal neighbours of `pos`, in the order
+    /// of [`Dir4::ALL`].
+    pub fn neighbours_4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.into()))
+    }
+
+    /// Iterates over the positions of the up to eight neighbours of `pos`, in the order of
+    /// [`Dir8::ALL`].
+    pub fn neighbours_8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir.into()))
+    }
+
+    /// Iterates over all positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over all cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Iterates over all cells with their positions, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    /// Returns the position of the first cell matching `pred`, row by row.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    /// Iterates over the positions of all cells matching `pred`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| pred(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Iterates over the cells starting at `pos` and moving `(dx, dy)` per step, until leaving the
+    /// grid.
+    pub fn ray(
+        &self,
+        pos: (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> impl Iterator<Item = &T> + '_ {
+        std::iter::successors(self.contains(pos).then_some(pos), move |&pos| {
+            self.offset(pos, (dx, dy))
+        })
+        .map(|pos| &self[pos])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates over the diagonals running from top left to bottom right, starting with the one
+    /// in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| (0, y))
+            .chain((1..self.width).map(|x| (x, 0)));
+        starts.map(|pos| self.ray(pos, (1, 1)))
+    }
+
+    /// Iterates over the diagonals running from top right to bottom left, starting with the one
+    /// in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(|y| (self.width - 1, y)));
+        starts.map(|pos| self.ray(pos, (-1, 1)))
+    }
+
+    /// Maps every cell to a new value.
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, swapping rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        match self.get((x, y)) {
+            Some(cell) => cell,
+            None => panic!(
+                "position ({x}, {y}) is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut((x, y)) {
+            Some(cell) => cell,
+            None => panic!("position ({x}, {y}) is outside of the {width}x{height} grid"),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidCell {
+        x: usize,
+        y: usize,
+        cell: char,
+    },
+}
+
+impl std::error::Error for GridError {}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "the grid is empty."),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} cells, but the lines before have {expected}."
+            ),
+            GridError::InvalidCell { x, y, cell } => {
+                write!(f, "invalid cell `{cell}` at ({x}, {y}).")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c).unwrap()
+    }
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::try_parse("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits[(0, 1)], 3);
+
+        assert_eq!(
+            Grid::parse("abc\nde", |c| c),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Grid::try_parse("12\n3x", |c| c.to_digit(10)),
+            Err(GridError::InvalidCell {
+                x: 1,
+                y: 1,
+                cell: 'x'
+            })
+        );
+        assert_eq!(Grid::parse("\n", |c| c), Err(GridError::Empty));
+    }
+
+    #[test]
+    #[should_panic(expected = "a grid needs at least one cell, got 0x3")]
+    fn test_new_zero_width() {
+        Grid::new(0, 3, '.');
+    }
+
+    #[test]
+    #[should_panic(expected = "a grid needs at least one cell, got 2x0")]
+    fn test_from_fn_zero_height() {
+        Grid::from_fn(2, 0, |_| '.');
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = grid();
+        assert_eq!(grid.get((1usize, 0usize)), Some(&'b'));
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((3i64, 0i64)), None);
+        assert_eq!(grid.get((0usize, 2usize)), None);
+
+        *grid.get_mut((0isize, 1isize)).unwrap() = 'x';
+        assert_eq!(grid.row(1), ['x', 'e', 'f']);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = grid();
+        assert_eq!(grid.find(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.find(|&c| c == 'z'), None);
+        assert_eq!(
+            grid.find_all(|&c| "aef".contains(c)).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        assert_eq!(collect(grid.column(1)), "be");
+        assert_eq!(
+            grid.columns().map(collect).collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals().map(collect).collect::<Vec<_>>(),
+            vec!["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals().map(collect).collect::<Vec<_>>(),
+            vec!["a", "bd", "ce", "f"]
+        );
+        assert_eq!(collect(grid.ray((2, 1), (-1, 0))), "fed");
+        assert_eq!(collect(grid.ray((3, 1), (-1, 0))), "");
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).row(0), ['A', 'B', 'C']);
+    }
+}
@@ -1,3 +1,10 @@
+pub mod days;
+pub mod grid;
+pub mod linear;
+pub mod math;
+pub mod parse;
+pub mod point;
+pub mod search;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
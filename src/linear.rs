@@ -0,0 +1,552 @@
+/// Exact solutions of small systems of linear equations, using Gaussian elimination over
+/// [`Rational`] numbers instead of floats.
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::math::{extended_gcd, gcd, lcm};
+
+fn checked<T>(value: Option<T>) -> T {
+    value.expect("rational arithmetic overflows i128")
+}
+
+/// A fraction of `i128`s in lowest terms, with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// Creates `num / den` in lowest terms. Panics if `den` is zero.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "the denominator of {num}/{den} is zero");
+        let g = gcd(num, den);
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Self {
+                num: checked(num.checked_neg()),
+                den: checked(den.checked_neg()),
+            }
+        } else {
+            Self { num, den }
+        }
+    }
+
+    pub fn numer(self) -> i128 {
+        self.num
+    }
+
+    pub fn denom(self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.den == 1
+    }
+
+    /// Returns the value if it is an integer.
+    pub fn to_integer(self) -> Option<i128> {
+        self.is_integer().then_some(self.num)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Rational {
+                fn from(n: $t) -> Self {
+                    Self { num: n.into(), den: 1 }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = checked(self.num.checked_mul(other.den));
+        let rhs = checked(other.num.checked_mul(self.den));
+        lhs.cmp(&rhs)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Self::Output {
+        let den = checked(lcm(self.den, rhs.den));
+        let lhs = checked(self.num.checked_mul(den / self.den));
+        let rhs = checked(rhs.num.checked_mul(den / rhs.den));
+        Rational::new(checked(lhs.checked_add(rhs)), den)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            num: checked(self.num.checked_neg()),
+            den: self.den,
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Self::Output {
+        // cancel first to keep the products small.
+        let (a, b) = (gcd(self.num, rhs.den).max(1), gcd(rhs.num, self.den).max(1));
+        Rational::new(
+            checked((self.num / a).checked_mul(rhs.num / b)),
+            checked((self.den / b).checked_mul(rhs.den / a)),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Rational) -> Self::Output {
+        assert!(!rhs.is_zero(), "division of {self} by zero");
+        self * Rational::new(rhs.den, rhs.num)
+    }
+}
+
+/// The solutions of a system of linear equations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SystemSolution {
+    /// Exactly one solution.
+    Unique(Vec<Rational>),
+    /// Every `particular + t_1 * directions[0] + t_2 * directions[1] + …` is a solution, with one
+    /// direction per free variable.
+    Infinite {
+        particular: Vec<Rational>,
+        directions: Vec<Vec<Rational>>,
+    },
+    /// The equations contradict each other.
+    Inconsistent,
+}
+
+/// Solves `matrix * x = rhs`, where each row of `matrix` holds the coefficients of one equation.
+///
+/// Panics if the rows differ in length or there is not one `rhs` per row.
+pub fn solve<T: Copy + Into<Rational>>(matrix: &[Vec<T>], rhs: &[T]) -> SystemSolution {
+    assert_eq!(
+        matrix.len(),
+        rhs.len(),
+        "expected one right-hand side per row"
+    );
+    let columns = matrix.first().map_or(0, Vec::len);
+    assert!(
+        matrix.iter().all(|row| row.len() == columns),
+        "all rows must have {columns} coefficients"
+    );
+
+    // the augmented matrix, reduced to row echelon form below.
+    let mut rows: Vec<Vec<Rational>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &b)| row.iter().chain([&b]).map(|&v| v.into()).collect())
+        .collect();
+
+    let mut pivots: Vec<usize> = vec![];
+    for column in 0..columns {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|&r| !rows[r][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+
+        let factor = rows[rank][column];
+        for value in &mut rows[rank] {
+            *value = *value / factor;
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if r == rank || factor.is_zero() {
+                continue;
+            }
+            for (value, &p) in row.iter_mut().zip(&pivot_row).skip(column) {
+                *value = *value - factor * p;
+            }
+        }
+        pivots.push(column);
+    }
+
+    // a remaining row reads `0 = b`.
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| !row[columns].is_zero())
+    {
+        return SystemSolution::Inconsistent;
+    }
+
+    let mut particular = vec![Rational::ZERO; columns];
+    for (row, &column) in pivots.iter().enumerate() {
+        particular[column] = rows[row][columns];
+    }
+    if pivots.len() == columns {
+        return SystemSolution::Unique(particular);
+    }
+
+    let directions = (0..columns)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut direction = vec![Rational::ZERO; columns];
+            direction[free] = Rational::ONE;
+            for (row, &column) in pivots.iter().enumerate() {
+                direction[column] = -rows[row][free];
+            }
+            direction
+        })
+        .collect();
+
+    SystemSolution::Infinite {
+        particular,
+        directions,
+    }
+}
+
+fn div_floor(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    -div_floor(-a, b)
+}
+
+/// Finds the non-negative integers `[x, y]` solving the equations `a * x + b * y = c`, given as
+/// rows `[a, b]` of `matrix` and `c` of `rhs`, that minimize `cost[0] * x + cost[1] * y`. With
+/// `max`, both `x` and `y` are at most `max`.
+///
+/// Returns the solution and its cost, or `None` if there is no such solution or the cost has no
+/// minimum.
+pub fn min_cost_integer_solution(
+    matrix: &[[i128; 2]],
+    rhs: &[i128],
+    cost: [i128; 2],
+    max: Option<i128>,
+) -> Option<([i128; 2], i128)> {
+    let total = |[x, y]: [i128; 2]| cost[0] * x + cost[1] * y;
+    let in_range = |v: i128| v >= 0 && max.is_none_or(|max| v <= max);
+    if !in_range(0) {
+        return None;
+    }
+    let mut matrix: Vec<Vec<i128>> = matrix.iter().map(|row| row.to_vec()).collect();
+    let mut rhs = rhs.to_vec();
+    if matrix.is_empty() {
+        // `solve` infers the number of unknowns from the rows, `0 * x + 0 * y = 0` leaves both free.
+        matrix.push(vec![0, 0]);
+        rhs.push(0);
+    }
+
+    let (particular, direction) = match solve(&matrix, &rhs) {
+        SystemSolution::Inconsistent => return None,
+        SystemSolution::Unique(solution) => {
+            let [x, y] = [solution[0].to_integer()?, solution[1].to_integer()?];
+            return (in_range(x) && in_range(y)).then(|| ([x, y], total([x, y])));
+        }
+        SystemSolution::Infinite {
+            particular,
+            directions,
+        } => match directions.as_slice() {
+            [direction] => (particular, direction.clone()),
+            // any presses work, so each is as low or as high as possible, depending on its cost.
+            _ => {
+                let pick = |cost: i128| if cost >= 0 { Some(0) } else { max };
+                let presses = [pick(cost[0])?, pick(cost[1])?];
+                return Some((presses, total(presses)));
+            }
+        },
+    };
+
+    // all solutions are `particular + t * direction`, which is a single equation
+    // `a * x + b * y = c` with integer coefficients.
+    let (a, b) = (direction[1], -direction[0]);
+    let c = a * particular[0] + b * particular[1];
+    let scale = [a, b, c].iter().try_fold(1, |l, r| lcm(l, r.denom()))?;
+    let [a, b, c] = [a, b, c].map(|r| r.numer() * (scale / r.denom()));
+
+    // a * x + b * y = c, solved as x = x0 + k * dx, y = y0 - k * dy.
+    let (g, u, v) = extended_gcd(a, b);
+    if c % g != 0 {
+        return None;
+    }
+    let (x0, y0) = (u * (c / g), v * (c / g));
+    let (dx, dy) = (b / g, a / g);
+
+    // the bounds of k keeping x and y non-negative and at most `max`.
+    let mut lo: Option<i128> = None;
+    let mut hi: Option<i128> = None;
+    let mut is_feasible = true;
+    let mut bound = |start: i128, step: i128| match step.cmp(&0) {
+        // start + k * step >= 0
+        Ordering::Greater => lo = lo.max(Some(div_ceil(-start, step))),
+        Ordering::Less => {
+            let k = div_floor(start, -step);
+            hi = Some(hi.map_or(k, |hi| hi.min(k)));
+        }
+        Ordering::Equal => is_feasible &= start >= 0,
+    };
+    bound(x0, dx);
+    bound(y0, -dy);
+    if let Some(max) = max {
+        bound(max - x0, -dx);
+        bound(max - y0, dy);
+    }
+    if !is_feasible {
+        return None;
+    }
+    if let (Some(lo), Some(hi)) = (lo, hi) {
+        if lo > hi {
+            return None;
+        }
+    }
+
+    let at = |k: i128| [x0 + k * dx, y0 - k * dy];
+    let slope = cost[0] * dx - cost[1] * dy;
+    let k = match slope.cmp(&0) {
+        Ordering::Greater => lo?,
+        Ordering::Less => hi?,
+        Ordering::Equal => lo.or(hi).unwrap_or(0),
+    };
+    Some((at(k), total(at(k))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(num: i128, den: i128) -> Rational {
+        Rational::new(num, den)
+    }
+
+    fn ints(values: &[i128]) -> Vec<Rational> {
+        values.iter().map(|&v| v.into()).collect()
+    }
+
+    #[test]
+    fn test_rational() {
+        assert_eq!(r(2, -4), r(-1, 2));
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(3, 4), r(-1, 4));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(-4, 9), r(-3, 2));
+        assert!(r(1, 3) < r(1, 2));
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(7, 3).to_integer(), None);
+        assert_eq!(r(-7, 3).to_string(), "-7/3");
+        assert_eq!(Rational::from(4).to_string(), "4");
+    }
+
+    #[test]
+    fn test_solve() {
+        // a 3x3 system with a fractional solution
+        let solution = solve(
+            &[vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]],
+            &[8, -11, -3],
+        );
+        assert_eq!(solution, SystemSolution::Unique(ints(&[2, 3, -1])));
+        assert_eq!(
+            solve(&[vec![3, 0], vec![0, 2]], &[1, 1]),
+            SystemSolution::Unique(vec![r(1, 3), r(1, 2)])
+        );
+
+        // collinear rows
+        assert_eq!(
+            solve(&[vec![1, 2], vec![2, 4]], &[3, 6]),
+            SystemSolution::Infinite {
+                particular: ints(&[3, 0]),
+                directions: vec![ints(&[-2, 1])],
+            }
+        );
+        assert_eq!(
+            solve(&[vec![1, 2], vec![2, 4]], &[3, 7]),
+            SystemSolution::Inconsistent
+        );
+
+        // more equations than unknowns
+        assert_eq!(
+            solve(&[vec![1], vec![2], vec![3]], &[2, 4, 6]),
+            SystemSolution::Unique(ints(&[2]))
+        );
+    }
+
+    #[test]
+    fn test_min_cost() {
+        // claw machines of day 13
+        assert_eq!(
+            min_cost_integer_solution(&[[94, 22], [34, 67]], &[8400, 5400], [3, 1], None),
+            Some(([80, 40], 280))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&[[26, 67], [66, 21]], &[12748, 12176], [3, 1], None),
+            None
+        );
+
+        // collinear buttons: 2x + 3y = 12 twice, with (0, 4), (3, 2) and (6, 0)
+        let collinear = [[2, 3], [4, 6]];
+        assert_eq!(
+            min_cost_integer_solution(&collinear, &[12, 24], [3, 1], None),
+            Some(([0, 4], 4))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&collinear, &[12, 24], [1, 3], None),
+            Some(([6, 0], 6))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&collinear, &[13, 26], [1, 1], None),
+            Some(([2, 3], 5))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&collinear, &[1, 2], [1, 1], None),
+            None
+        );
+
+        // the first button does nothing, y is fixed
+        assert_eq!(
+            min_cost_integer_solution(&[[0, 5]], &[10], [3, 1], None),
+            Some(([0, 2], 2))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&[[0, 5]], &[-10], [3, 1], None),
+            None
+        );
+        // x - y = 1 has no cheapest solution with a negative cost
+        assert_eq!(
+            min_cost_integer_solution(&[[1, -1]], &[1], [1, -2], None),
+            None
+        );
+        assert_eq!(
+            min_cost_integer_solution(&[[1, -1]], &[1], [1, 1], None),
+            Some(([1, 0], 1))
+        );
+
+        // neither button does anything
+        assert_eq!(
+            min_cost_integer_solution(&[[0, 0]], &[0], [2, 1], None),
+            Some(([0, 0], 0))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&[[0, 0]], &[0], [2, -1], None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_min_cost_max() {
+        assert_eq!(
+            min_cost_integer_solution(&[[94, 22], [34, 67]], &[8400, 5400], [3, 1], Some(50)),
+            None
+        );
+
+        // 2x + 3y = 12 without (6, 0)
+        let collinear = [[2, 3], [4, 6]];
+        assert_eq!(
+            min_cost_integer_solution(&collinear, &[12, 24], [1, 3], Some(5)),
+            Some(([3, 2], 9))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&collinear, &[12, 24], [1, 3], Some(1)),
+            None
+        );
+
+        // the cheapest presses of a claw machine with collinear buttons exceed 100
+        let machine = [[47, 14], [47, 14]];
+        assert_eq!(
+            min_cost_integer_solution(&machine, &[5065, 5065], [3, 1], None),
+            Some(([103, 16], 325))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&machine, &[5065, 5065], [3, 1], Some(100)),
+            Some(([89, 63], 330))
+        );
+
+        assert_eq!(
+            min_cost_integer_solution(&[[0, 0]], &[0], [2, -1], Some(7)),
+            Some(([0, 7], -7))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&[[1, 1]], &[0], [1, 1], Some(-1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_min_cost_integer_solution_without_equations() {
+        assert_eq!(
+            min_cost_integer_solution(&[], &[], [3, 1], None),
+            Some(([0, 0], 0))
+        );
+        assert_eq!(
+            min_cost_integer_solution(&[], &[], [-1, 2], Some(5)),
+            Some(([5, 0], -5))
+        );
+        assert_eq!(min_cost_integer_solution(&[], &[], [-1, 2], None), None);
+    }
+
+    #[test]
+    fn test_example() {
+        // 2x + y = 5, x - y = 1
+        let solution = solve(&[vec![2, 1], vec![1, -1]], &[5, 1]);
+        assert_eq!(
+            solution,
+            SystemSolution::Unique(vec![Rational::from(2), Rational::from(1)])
+        );
+
+        // the cheapest presses of buttons moving by 2 and 3 to reach 12, costing 3 and 1 tokens
+        assert_eq!(
+            min_cost_integer_solution(&[[2, 3]], &[12], [3, 1], None),
+            Some(([0, 4], 4))
+        );
+    }
+}
@@ -1,14 +1,18 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, determinism, download, fuzz, inputs, leaderboard, read, scaffold, solve, splits, time,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::commands::inputs;
+    use advent_of_code::template::day_set::DaySet;
+    use advent_of_code::template::profiles::{parse_profiles, Profile};
+    use advent_of_code::template::visualize::{Settings, DEFAULT_FPS};
+    use advent_of_code::template::{report::ReportFormat, Config, Day};
+    use std::{path::PathBuf, process};
 
     pub enum AppArguments {
         Download {
@@ -16,6 +20,8 @@ mod args {
         },
         Read {
             day: Day,
+            refresh: bool,
+            part: Option<u8>,
         },
         Scaffold {
             day: Day,
@@ -27,34 +33,76 @@ mod args {
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            variant: Option<String>,
+            visualize: Option<Settings>,
+            verbosity: u8,
         },
         All {
             release: bool,
+            reports: Vec<ReportFormat>,
+            record: bool,
+            days: Option<DaySet>,
         },
         Time {
             all: bool,
-            day: Option<Day>,
+            days: Option<DaySet>,
             store: bool,
+            from_bench: bool,
+            variants: bool,
+            profiles: Vec<Profile>,
+        },
+        FuzzDay {
+            day: Day,
+            cases: Option<u32>,
+            seed: Option<u64>,
+            size: Option<usize>,
+        },
+        CheckDeterminism {
+            day: Day,
+            runs: u32,
+        },
+        Leaderboard {
+            file: Option<PathBuf>,
+            fetch: bool,
+            day: Option<Day>,
+        },
+        Splits,
+        Inputs {
+            action: inputs::Action,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
+        let defaults = &Config::get().defaults;
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                release: parse_release(&mut args, defaults.release)?,
+                reports: args.values_from_str("--report")?,
+                record: args.contains("--record"),
+                days: args.opt_free_from_str()?,
             },
             Some("time") => {
                 let all = args.contains("--all");
-                let store = args.contains("--store");
+                let store = args.contains("--store") || defaults.store;
+                let from_bench = args.contains("--from-bench");
+                let variants = args.contains("--variants");
+                let profiles = args
+                    .opt_value_from_fn("--profiles", parse_profiles)?
+                    .unwrap_or_default();
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    days: args.opt_free_from_str()?,
                     store,
+                    from_bench,
+                    variants,
+                    profiles,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -62,6 +110,8 @@ mod args {
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
+                refresh: args.contains("--refresh"),
+                part: args.opt_value_from_str("--part")?,
             },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
@@ -70,12 +120,58 @@ mod args {
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
+                release: parse_release(&mut args, defaults.release)?,
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                variant: args.opt_value_from_str("--variant")?,
+                visualize: match args.opt_value_from_str("--visualize")? {
+                    Some(output) => Some(Settings {
+                        output,
+                        fps: args.opt_value_from_str("--fps")?.unwrap_or(DEFAULT_FPS),
+                    }),
+                    None => None,
+                },
+                verbosity: if args.contains("-vv") {
+                    2
+                } else {
+                    args.contains(["-v", "--verbose"]).into()
+                },
+            },
+            Some("fuzz-day") => AppArguments::FuzzDay {
+                day: args.free_from_str()?,
+                cases: args.opt_value_from_str("--cases")?,
+                seed: args.opt_value_from_str("--seed")?,
+                size: args.opt_value_from_str("--size")?,
             },
+            Some("check-determinism") => AppArguments::CheckDeterminism {
+                day: args.free_from_str()?,
+                runs: args.opt_value_from_str("--runs")?.unwrap_or(20),
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                fetch: args.contains("--fetch"),
+                day: args.opt_value_from_str("--day")?,
+                file: args.opt_free_from_str()?,
+            },
+            Some("splits") => AppArguments::Splits,
+            Some("inputs") => {
+                let action = match args.subcommand()?.as_deref() {
+                    Some("encrypt") => inputs::Action::Encrypt,
+                    Some("decrypt") => inputs::Action::Decrypt {
+                        overwrite: args.contains("--overwrite"),
+                    },
+                    _ => {
+                        eprintln!(
+                            "Unexpected command-line input. Format: cargo inputs encrypt|decrypt"
+                        );
+                        process::exit(1);
+                    }
+                };
+                AppArguments::Inputs { action }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -93,6 +189,17 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// `--release`, or `defaults.release` of `aoc.toml` unless `--debug` turns it off.
+    fn parse_release(
+        args: &mut pico_args::Arguments,
+        default: bool,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        match (args.contains("--release"), args.contains("--debug")) {
+            (true, true) => Err("--release and --debug cannot be combined.".into()),
+            (release, debug) => Ok(release || (default && !debug)),
+        }
+    }
 }
 
 fn main() {
@@ -102,10 +209,22 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All {
+                release,
+                reports,
+                record,
+                days,
+            } => all::handle(release, &reports, record, days),
+            AppArguments::Time {
+                days,
+                all,
+                store,
+                from_bench,
+                variants,
+                profiles,
+            } => time::handle(days, all, store, from_bench, variants, &profiles),
             AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::Read { day, refresh, part } => read::handle(day, refresh, part),
             AppArguments::Scaffold {
                 day,
                 download,
@@ -121,24 +240,22 @@ fn main() {
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                variant,
+                visualize,
+                verbosity,
+            } => solve::handle(day, release, dhat, submit, variant, visualize, verbosity),
+            AppArguments::FuzzDay {
+                day,
+                cases,
+                seed,
+                size,
+            } => fuzz::handle(day, cases, seed, size),
+            AppArguments::CheckDeterminism { day, runs } => determinism::handle(day, runs),
+            AppArguments::Leaderboard { file, fetch, day } => leaderboard::handle(file, fetch, day),
+            AppArguments::Splits => splits::handle(),
+            AppArguments::Inputs { action } => inputs::handle(action),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
@@ -0,0 +1,347 @@
+/// Number theory for primitive integers: gcd, lcm, modular arithmetic and the Chinese remainder
+/// theorem.
+///
+/// Every function works for all primitive integer types up to `i128`/`u128`; those needing
+/// negative numbers, e.g. [`extended_gcd`], take signed types only. Results that may not fit
+/// into the type are returned as `Option`, intermediate steps never overflow.
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// A primitive integer type.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    /// The absolute value, or `None` if it does not fit, i.e. for `MIN` of signed types.
+    fn checked_abs(self) -> Option<Self>;
+}
+
+/// A primitive signed integer type.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+
+                #[allow(unused_comparisons)]
+                fn checked_abs(self) -> Option<Self> {
+                    if self < 0 {
+                        Self::ZERO.checked_sub(self)
+                    } else {
+                        Some(self)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+/// `a % b`, which is `0` for `b == -1` instead of overflowing for `MIN % -1`.
+fn rem<T: Integer>(a: T, b: T) -> T {
+    if T::ZERO.checked_sub(T::ONE) == Some(b) {
+        T::ZERO
+    } else {
+        a % b
+    }
+}
+
+/// The gcd, or `None` if its absolute value does not fit.
+fn checked_gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, rem(a, b));
+    }
+    a.checked_abs()
+}
+
+/// The greatest common divisor, which is never negative. `gcd(0, 0)` is `0`.
+///
+/// Panics if the result does not fit, i.e. for `gcd(MIN, 0)` of a signed type.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b)
+        .unwrap_or_else(|| panic!("the absolute value of the gcd of {a:?} and {b:?} overflows"))
+}
+
+/// The least common multiple, which is never negative, or `None` if it overflows. `lcm(0, x)` is
+/// `0`.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / checked_gcd(a, b)?).checked_mul(b)?.checked_abs()
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g`, where `g` is the gcd of `a` and `b`.
+///
+/// Panics if `g` does not fit, i.e. for `extended_gcd(MIN, 0)`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        // stop before dividing by a divisor of `old_r`, which may be `MIN / -1`.
+        if rem(old_r, r) == T::ZERO {
+            (old_r, old_x, old_y) = (r, x, y);
+            break;
+        }
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < T::ZERO {
+        let g = old_r.checked_abs().unwrap_or_else(|| {
+            panic!("the absolute value of the gcd of {a:?} and {b:?} overflows")
+        });
+        (g, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `(a + b) mod m` for `a` and `b` in `0..m`, without overflowing.
+fn add_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `(a * b) mod m` in `0..m`, without overflowing. Panics unless `m` is positive.
+pub fn mul_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    assert!(m > T::ZERO, "the modulus must be positive, got {m:?}");
+    let (mut a, mut b) = (a.rem_euclid(m), b.rem_euclid(m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // double and add, as the product does not fit.
+    let two = T::ONE + T::ONE;
+    let mut result = T::ZERO;
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b / two;
+    }
+    result
+}
+
+/// `base^exp mod m` in `0..m`. Panics unless `m` is positive and `exp` is not negative.
+pub fn mod_pow<T: Integer>(base: T, exp: T, m: T) -> T {
+    assert!(
+        exp >= T::ZERO,
+        "the exponent must not be negative, got {exp:?}"
+    );
+    let two = T::ONE + T::ONE;
+    let (mut base, mut exp) = (base, exp);
+    let mut result = T::ONE % m;
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp = exp / two;
+    }
+    result.rem_euclid(m)
+}
+
+/// The inverse of `a` modulo `m` in `0..m`, or `None` if `a` and `m` are not coprime. Panics unless
+/// `m` is positive.
+pub fn mod_inv<T: Signed>(a: T, m: T) -> Option<T> {
+    assert!(m > T::ZERO, "the modulus must be positive, got {m:?}");
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == T::ONE).then(|| x.rem_euclid(m))
+}
+
+/// Solves the congruences `x ≡ r (mod m)` for every `(r, m)` with the Chinese remainder theorem.
+/// The moduli do not need to be coprime.
+///
+/// Returns `(x, lcm)`, where `x` in `0..lcm` is the smallest solution and all solutions are
+/// `x + k * lcm`, or `None` if the congruences contradict each other or `lcm` overflows. Panics
+/// unless all moduli are positive.
+pub fn crt<T: Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    let (mut x, mut lcm) = (T::ZERO, T::ONE);
+    for (r, m) in congruences {
+        assert!(m > T::ZERO, "the modulus must be positive, got {m:?}");
+        let r = r.rem_euclid(m);
+
+        // find k with x + lcm * k ≡ r (mod m), i.e. lcm * k ≡ r - x (mod m)
+        let g = gcd(lcm, m);
+        let diff = r.checked_sub(x.rem_euclid(m))?;
+        if diff % g != T::ZERO {
+            return None;
+        }
+        let m_g = m / g;
+        let k = mul_mod(diff / g, mod_inv(lcm / g, m_g)?, m_g);
+
+        let next = (lcm / g).checked_mul(m)?;
+        x = add_mod(x, mul_mod(lcm, k, next), next);
+        lcm = next;
+    }
+    Some((x, lcm))
+}
+
+/// The integer square root, i.e. the largest `r` with `r * r <= n`. Panics if `n` is negative.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    assert!(n >= T::ZERO, "cannot take the square root of {n:?}");
+    if n < T::ONE + T::ONE {
+        return n;
+    }
+    // Newton's method from above, written as `q + (x - q) / 2` to avoid overflowing.
+    let two = T::ONE + T::ONE;
+    let mut x = n;
+    loop {
+        let q = n / x;
+        if x <= q {
+            return x;
+        }
+        x = q + (x - q) / two;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(0i8, -5), 5);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4i32, -6), Some(12));
+        assert_eq!(lcm(0u64, 7), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(1i128 << 100, 3), Some(3 << 100));
+
+        // `MIN % -1` overflows, but the gcd is 1.
+        assert_eq!(gcd(i64::MIN, -1), 1);
+        assert_eq!(gcd(-1, i64::MIN), 1);
+        assert_eq!(lcm(i64::MIN, -1), None);
+        assert_eq!(lcm(i64::MIN, 0), Some(0));
+        assert_eq!(lcm(i64::MIN, i64::MIN), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_gcd_overflow() {
+        gcd(i8::MIN, 0);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let cases = [
+            (240i64, 46),
+            (-240, 46),
+            (17, 0),
+            (0, -3),
+            (7, 13),
+            (i64::MIN, -1),
+            (-1, i64::MIN),
+            (i64::MIN, 3),
+            (i64::MIN, i64::MAX),
+        ];
+        for (a, b) in cases {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_extended_gcd_overflow() {
+        extended_gcd(i64::MIN, 0);
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mod_inv(3i32, 11), Some(4));
+        assert_eq!(mod_inv(-3i32, 11), Some(7));
+        assert_eq!(mod_inv(6i64, 9), None);
+        assert_eq!(mod_pow(2u32, 10, 1000), 24);
+        assert_eq!(mod_pow(-2i32, 3, 5), 2);
+        assert_eq!(mod_pow(5u8, 0, 1), 0);
+        // the products overflow u64 and need the double and add fallback.
+        let p = 18_446_744_073_709_551_557u64;
+        assert_eq!(mod_pow(3u64, p - 1, p), 1);
+        assert_eq!(mul_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // not coprime, but consistent
+        assert_eq!(crt([(2i32, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1i32, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1i64, 5)]), Some((4, 5)));
+        assert_eq!(crt(Vec::<(i32, i32)>::new()), Some((0, 1)));
+        assert_eq!(crt([(1i8, 13), (2, 11)]), None);
+
+        let (a, b) = (1_000_000_007i128, 998_244_353i128);
+        let (x, m) = crt([(5i128, a), (7, b)]).unwrap();
+        assert_eq!((x % a, x % b, m), (5, 7, a * b));
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0u32), 0);
+        assert_eq!(isqrt(15u32), 3);
+        assert_eq!(isqrt(16i64), 4);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt(i8::MAX), 11);
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(lcm(4u64, 6), Some(12));
+        assert_eq!(mod_inv(3i64, 7), Some(5));
+        // x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7)
+        assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    }
+}
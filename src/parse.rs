@@ -0,0 +1,308 @@
+/// Helpers for parsing puzzle inputs: integers anywhere in a line, blank-line separated sections
+/// and lines following a pattern.
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The short name of `T`, e.g. `i64` or `String`.
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Parses `s` into `T`, describing the failure in an error message.
+fn parse_value<T: FromStr>(s: &str) -> Result<T, String> {
+    s.parse()
+        .map_err(|_| format!("`{s}` is not a valid {}", type_name::<T>()))
+}
+
+/// Iterates over all integers in `s`, skipping everything else. A `-` directly before the digits
+/// is read as a sign unless it follows a letter or digit, so `1-3` yields `1` and `3`. Types that
+/// cannot be negative, e.g. `u32`, never read a sign, so `x=-3` yields `3`.
+///
+/// Panics if an integer does not fit into `T`.
+pub fn ints<T: FromStr>(s: &str) -> impl Iterator<Item = T> + '_ {
+    let bytes = s.as_bytes();
+    let is_signed = "-1".parse::<T>().is_ok();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+
+        let mut start = i;
+        if is_signed
+            && start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        Some(parse_value(&s[start..i]).unwrap_or_else(|e| panic!("{e}.")))
+    })
+}
+
+/// Iterates over the sections of `input` that are separated by one or more blank lines, without
+/// their trailing newlines.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        // skip blank lines before the section.
+        while let Some((line, next)) = rest.split_once('\n') {
+            if !line.trim().is_empty() {
+                break;
+            }
+            rest = next;
+        }
+        if rest.trim().is_empty() {
+            return None;
+        }
+
+        let mut end = rest.len();
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                end = offset;
+                break;
+            }
+            offset += line.len();
+        }
+
+        let section = rest[..end].trim_end_matches(['\n', '\r']);
+        rest = &rest[end..];
+        Some(section)
+    })
+}
+
+/// A line format with `{}` placeholders, e.g. `"Button A: X+{}, Y+{}"`.
+///
+/// A placeholder matches everything up to the first occurrence of the text following it, or the
+/// rest of the line if it is last. The matched fields are parsed into a tuple with
+/// [`Pattern::parse`].
+#[derive(Clone, Debug)]
+pub struct Pattern<'p> {
+    pattern: &'p str,
+    literals: Vec<&'p str>,
+}
+
+impl<'p> Pattern<'p> {
+    pub fn new(pattern: &'p str) -> Self {
+        Self {
+            pattern,
+            literals: pattern.split("{}").collect(),
+        }
+    }
+
+    /// Returns the text matched by each placeholder.
+    pub fn fields<'a>(&self, input: &'a str) -> Result<Vec<&'a str>, PatternError> {
+        let error = |message: String| PatternError {
+            pattern: self.pattern.to_string(),
+            input: input.to_string(),
+            message,
+        };
+
+        let (first, literals) = self.literals.split_first().expect("split yields a literal");
+        let mut rest = input
+            .strip_prefix(first)
+            .ok_or_else(|| error(format!("expected the line to start with `{first}`")))?;
+
+        let mut fields = vec![];
+        for (i, literal) in literals.iter().enumerate() {
+            let is_last = i == literals.len() - 1;
+            let end = match (literal.is_empty(), is_last) {
+                (true, true) => Some(rest.len()),
+                (true, false) => {
+                    return Err(error(
+                        "placeholders must be separated by some text".to_string(),
+                    ))
+                }
+                (false, true) => rest.ends_with(literal).then(|| rest.len() - literal.len()),
+                (false, false) => rest.find(literal),
+            };
+            let column = input.len() - rest.len() + 1;
+            let Some(end) = end.filter(|&end| end > 0) else {
+                return Err(error(match end {
+                    Some(_) => format!("field {} at column {column} is empty", i + 1),
+                    None if is_last => format!("expected the line to end with `{literal}`"),
+                    None => format!("expected `{literal}` after column {column}"),
+                }));
+            };
+            fields.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+
+        Ok(fields)
+    }
+
+    /// Parses the fields of `input` into a tuple with one element per placeholder.
+    pub fn parse<T: FromFields>(&self, input: &str) -> Result<T, PatternError> {
+        let fields = self.fields(input)?;
+        T::from_fields(&fields).map_err(|message| PatternError {
+            pattern: self.pattern.to_string(),
+            input: input.to_string(),
+            message,
+        })
+    }
+}
+
+/// A tuple that can be parsed from the fields of a [`Pattern`].
+pub trait FromFields: Sized {
+    fn from_fields(fields: &[&str]) -> Result<Self, String>;
+}
+
+macro_rules! impl_from_fields {
+    ($len:literal: $($t:ident $i:tt),+) => {
+        impl<$($t: FromStr),+> FromFields for ($($t,)+) {
+            fn from_fields(fields: &[&str]) -> Result<Self, String> {
+                if fields.len() != $len {
+                    return Err(format!(
+                        "the pattern has {} fields, but {} were expected",
+                        fields.len(),
+                        $len
+                    ));
+                }
+                Ok(($(
+                    parse_value::<$t>(fields[$i])
+                        .map_err(|e| format!("field {}: {e}", $i + 1))?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1: A 0);
+impl_from_fields!(2: A 0, B 1);
+impl_from_fields!(3: A 0, B 1, C 2);
+impl_from_fields!(4: A 0, B 1, C 2, D 3);
+impl_from_fields!(5: A 0, B 1, C 2, D 3, E 4);
+impl_from_fields!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_fields!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_fields!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// A line that does not match a [`Pattern`], or whose fields could not be parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PatternError {
+    pub pattern: String,
+    pub input: String,
+    pub message: String,
+}
+
+impl std::error::Error for PatternError {}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "could not parse `{}` as `{}`: {}.",
+            self.input, self.pattern, self.message
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        let all = |s| ints::<i64>(s).collect::<Vec<_>>();
+        assert_eq!(all("p=0,4 v=3,-3"), vec![0, 4, 3, -3]);
+        assert_eq!(all("190: 10 19"), vec![190, 10, 19]);
+        assert_eq!(all("1-3 a-5 -7"), vec![1, 3, 5, -7]);
+        assert_eq!(all("no numbers"), vec![]);
+        assert_eq!(ints::<u8>("47|53").collect::<Vec<_>>(), vec![47, 53]);
+    }
+
+    #[test]
+    fn test_ints_unsigned() {
+        assert_eq!(ints::<u32>("x=-3").collect::<Vec<_>>(), vec![3]);
+        assert_eq!(
+            ints::<usize>("p=0,4 v=3,-3").collect::<Vec<_>>(),
+            vec![0, 4, 3, 3]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`300` is not a valid u8")]
+    fn test_ints_overflow() {
+        ints::<u8>("1 300").for_each(drop);
+    }
+
+    #[test]
+    fn test_sections() {
+        let all = |s| sections(s).collect::<Vec<_>>();
+        assert_eq!(all("a\nb\n\nc"), vec!["a\nb", "c"]);
+        assert_eq!(all("\n\na\n\n\n\nb\n\n"), vec!["a", "b"]);
+        assert_eq!(all("a\r\nb\r\n\r\nc\r\n"), vec!["a\r\nb", "c"]);
+        assert_eq!(all("a\n  \nb"), vec!["a", "b"]);
+        assert_eq!(all(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_pattern() {
+        let pattern = Pattern::new("Button {}: X+{}, Y+{}");
+        assert_eq!(
+            pattern.parse::<(char, u32, u32)>("Button A: X+94, Y+34"),
+            Ok(('A', 94, 34))
+        );
+        assert_eq!(
+            Pattern::new("{}: {}").parse::<(u64, String)>("190: 10 19"),
+            Ok((190, "10 19".to_string()))
+        );
+        assert_eq!(
+            Pattern::new("{}|{}").fields("47|53|1"),
+            Ok(vec!["47", "53|1"])
+        );
+    }
+
+    #[test]
+    fn test_pattern_errors() {
+        let pattern = Pattern::new("p={},{} v={},{}");
+        let message = |input| {
+            pattern
+                .parse::<(i32, i32, i32, i32)>(input)
+                .unwrap_err()
+                .message
+        };
+        assert_eq!(
+            message("q=0,4 v=3,-3"),
+            "expected the line to start with `p=`"
+        );
+        assert_eq!(message("p=0,4 w=3,-3"), "expected ` v=` after column 5");
+        assert_eq!(message("p=,4 v=3,-3"), "field 1 at column 3 is empty");
+        assert_eq!(message("p=0,x v=3,-3"), "field 2: `x` is not a valid i32");
+        assert_eq!(
+            pattern
+                .parse::<(i32, i32)>("p=0,4 v=3,-3")
+                .unwrap_err()
+                .message,
+            "the pattern has 4 fields, but 2 were expected"
+        );
+        assert_eq!(
+            Pattern::new("({})")
+                .parse::<(i32,)>("(1")
+                .unwrap_err()
+                .to_string(),
+            "could not parse `(1` as `({})`: expected the line to end with `)`."
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(ints::<i32>("x=3, y=-12").collect::<Vec<_>>(), vec![3, -12]);
+        assert_eq!(
+            sections("a\nb\n\nc\n").collect::<Vec<_>>(),
+            vec!["a\nb", "c"]
+        );
+
+        let robot: (i64, i64, i64, i64) = Pattern::new("p={},{} v={},{}")
+            .parse("p=0,4 v=3,-3")
+            .unwrap();
+        assert_eq!(robot, (0, 4, 3, -3));
+    }
+}
@@ -0,0 +1,436 @@
+/// Points and compass directions on a grid whose `y` axis points down, as in puzzle inputs.
+///
+/// [`Dir4`] and [`Dir8`] convert to indices, so state tables can be plain arrays indexed by
+/// direction, e.g. `let mut seen = [false; 4]; seen[Dir4::North] = true;`.
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::Coordinate;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The number of orthogonal steps between two points.
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of steps between two points when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Iterates over the four orthogonal neighbours in the order of [`Dir4::ALL`].
+    pub fn neighbours_4(self) -> impl Iterator<Item = Point> {
+        Dir4::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    /// Iterates over all eight neighbours in the order of [`Dir8::ALL`].
+    pub fn neighbours_8(self) -> impl Iterator<Item = Point> {
+        Dir8::ALL.into_iter().map(move |dir| self + dir)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl Coordinate for Point {
+    fn xy(self) -> Option<(usize, usize)> {
+        (self.x, self.y).xy()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+/// Implements moving a point in a direction, and indexing arrays with one entry per direction.
+macro_rules! impl_direction {
+    ($dir:ty, $n:literal) => {
+        impl Add<$dir> for Point {
+            type Output = Point;
+
+            fn add(self, rhs: $dir) -> Self::Output {
+                self + rhs.delta()
+            }
+        }
+
+        impl Sub<$dir> for Point {
+            type Output = Point;
+
+            fn sub(self, rhs: $dir) -> Self::Output {
+                self - rhs.delta()
+            }
+        }
+
+        impl AddAssign<$dir> for Point {
+            fn add_assign(&mut self, rhs: $dir) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Mul<i64> for $dir {
+            type Output = Point;
+
+            fn mul(self, rhs: i64) -> Self::Output {
+                self.delta() * rhs
+            }
+        }
+
+        impl From<$dir> for (isize, isize) {
+            fn from(dir: $dir) -> Self {
+                let Point { x, y } = dir.delta();
+                (x as isize, y as isize)
+            }
+        }
+
+        impl<T> Index<$dir> for [T; $n] {
+            type Output = T;
+
+            fn index(&self, dir: $dir) -> &Self::Output {
+                &self[dir.index()]
+            }
+        }
+
+        impl<T> IndexMut<$dir> for [T; $n] {
+            fn index_mut(&mut self, dir: $dir) -> &mut Self::Output {
+                &mut self[dir.index()]
+            }
+        }
+    };
+}
+
+/// One of the four orthogonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// The position of the direction in [`Dir4::ALL`].
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The direction at `index` in [`Dir4::ALL`], wrapping around.
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 4]
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir4::North => Point::new(0, -1),
+            Dir4::East => Point::new(1, 0),
+            Dir4::South => Point::new(0, 1),
+            Dir4::West => Point::new(-1, 0),
+        }
+    }
+
+    /// Turns by 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 3)
+    }
+
+    /// Turns by 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::East | Dir4::West)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// The arrow drawn for the direction in puzzle inputs, one of `^>v<`.
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::North => '^',
+            Dir4::East => '>',
+            Dir4::South => 'v',
+            Dir4::West => '<',
+        }
+    }
+}
+
+impl_direction!(Dir4, 4);
+
+impl TryFrom<char> for Dir4 {
+    type Error = ParseDirectionError;
+
+    /// Parses arrows (`^>v<`), compass points (`NESW`) and `UDLR`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Dir4::North),
+            '>' | 'E' | 'R' => Ok(Dir4::East),
+            'v' | 'S' | 'D' => Ok(Dir4::South),
+            '<' | 'W' | 'L' => Ok(Dir4::West),
+            c => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl std::str::FromStr for Dir4 {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+/// One of the eight compass directions, including diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// The position of the direction in [`Dir8::ALL`].
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The direction at `index` in [`Dir8::ALL`], wrapping around.
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index % 8]
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir8::North => Point::new(0, -1),
+            Dir8::NorthEast => Point::new(1, -1),
+            Dir8::East => Point::new(1, 0),
+            Dir8::SouthEast => Point::new(1, 1),
+            Dir8::South => Point::new(0, 1),
+            Dir8::SouthWest => Point::new(-1, 1),
+            Dir8::West => Point::new(-1, 0),
+            Dir8::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Turns by 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 7)
+    }
+
+    /// Turns by 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::from_index(self.index() + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+}
+
+impl_direction!(Dir8, 8);
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::from_index(dir.index() * 2)
+    }
+}
+
+impl std::str::FromStr for Dir8 {
+    type Err = ParseDirectionError;
+
+    /// Parses compass points (`N`, `NE`, …) and anything [`Dir4`] parses.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Dir8::NorthEast),
+            "SE" => Ok(Dir8::SouthEast),
+            "SW" => Ok(Dir8::SouthWest),
+            "NW" => Ok(Dir8::NorthWest),
+            s => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl std::error::Error for ParseDirectionError {}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid direction `{}`, expected an arrow, a compass point or one of `UDLR`.",
+            self.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point::new(2, 3);
+        assert_eq!(p + Point::new(1, -1), Point::new(3, 2));
+        assert_eq!(p - Point::new(1, -1), Point::new(1, 4));
+        assert_eq!(p * 3, Point::new(6, 9));
+        assert_eq!(-p, Point::new(-2, -3));
+        assert_eq!(p + Dir4::North, Point::new(2, 2));
+        assert_eq!(p + Dir8::SouthWest, Point::new(1, 4));
+        assert_eq!(p + Dir4::East * 4, Point::new(6, 3));
+
+        let mut q = p;
+        q += Dir4::West;
+        q -= Point::new(0, 1);
+        assert_eq!(q, Point::new(1, 2));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, -2), Point::new(-3, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point::ORIGIN.neighbours_8().count(), 8);
+        assert!(Point::ORIGIN
+            .neighbours_4()
+            .all(|n| n.manhattan(Point::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir4::West.turn_right(), Dir4::North);
+        assert_eq!(Dir4::East.reverse(), Dir4::West);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::NorthWest.turn_right(), Dir8::North);
+        assert_eq!(Dir8::NorthEast.reverse(), Dir8::SouthWest);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.delta(), Dir8::from(dir).delta());
+            assert_eq!(dir.reverse().delta(), -dir.delta());
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        for (s, dir) in [("^", Dir4::North), ("E", Dir4::East), ("v", Dir4::South)] {
+            assert_eq!(s.parse(), Ok(dir));
+        }
+        assert_eq!(Dir4::try_from('L'), Ok(Dir4::West));
+        assert_eq!("NW".parse(), Ok(Dir8::NorthWest));
+        assert_eq!("D".parse(), Ok(Dir8::South));
+        assert!("x".parse::<Dir4>().is_err());
+        assert!("NE".parse::<Dir4>().is_err());
+        assert_eq!(Dir4::ALL.map(Dir4::arrow), ['^', '>', 'v', '<']);
+    }
+
+    #[test]
+    fn test_indices() {
+        let mut seen = [0; 4];
+        seen[Dir4::South] += 1;
+        assert_eq!(seen, [0, 0, 1, 0]);
+        assert_eq!(Dir4::from_index(Dir4::West.index()), Dir4::West);
+        assert_eq!([1, 2, 3, 4, 5, 6, 7, 8][Dir8::West], 7);
+        assert_eq!(<(isize, isize)>::from(Dir8::NorthEast), (1, -1));
+    }
+}
@@ -0,0 +1,394 @@
+/// Breadth-first search, Dijkstra and A* over a graph given by a `successors` function.
+///
+/// A search starts from one or more states and stops once every goal state with the optimal cost
+/// has been reached. Passing `|_| false` as goal explores everything reachable, e.g. for a flood
+/// fill. With [`Search::all_paths`], every optimal predecessor of a state is recorded instead of
+/// one, which answers "which states lie on some optimal path" and "how many optimal paths exist".
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cost of an edge or a path. Costs must not be negative, and `Default` is zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// The start of a search. See the [module documentation](self).
+pub struct Search<S> {
+    starts: Vec<S>,
+    all_paths: bool,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    pub fn new(starts: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            starts: starts.into_iter().collect(),
+            all_paths: false,
+        }
+    }
+
+    /// Records all optimal predecessors of every state, which is needed by
+    /// [`SearchResult::on_optimal_paths`] and [`SearchResult::count_optimal_paths`].
+    pub fn all_paths(mut self) -> Self {
+        self.all_paths = true;
+        self
+    }
+
+    /// Searches a graph whose edges all cost one step.
+    pub fn bfs<I>(
+        self,
+        mut successors: impl FnMut(&S) -> I,
+        is_goal: impl FnMut(&S) -> bool,
+    ) -> SearchResult<S, usize>
+    where
+        I: IntoIterator<Item = S>,
+    {
+        self.run(
+            |s| successors(s).into_iter().map(|s| (s, 1)),
+            |_| 0,
+            is_goal,
+            Frontier::Queue(VecDeque::new()),
+        )
+    }
+
+    /// Searches a graph with weighted edges.
+    pub fn dijkstra<C: Cost, I>(
+        self,
+        successors: impl FnMut(&S) -> I,
+        is_goal: impl FnMut(&S) -> bool,
+    ) -> SearchResult<S, C>
+    where
+        I: IntoIterator<Item = (S, C)>,
+    {
+        self.run(
+            successors,
+            |_| C::default(),
+            is_goal,
+            Frontier::Heap(BinaryHeap::new()),
+        )
+    }
+
+    /// Searches a graph with weighted edges, guided by a `heuristic` estimating the remaining cost
+    /// to a goal. The heuristic must never overestimate and must be consistent, i.e. not drop by
+    /// more than the cost of an edge, or the result may not be optimal.
+    pub fn astar<C: Cost, I>(
+        self,
+        successors: impl FnMut(&S) -> I,
+        heuristic: impl FnMut(&S) -> C,
+        is_goal: impl FnMut(&S) -> bool,
+    ) -> SearchResult<S, C>
+    where
+        I: IntoIterator<Item = (S, C)>,
+    {
+        self.run(
+            successors,
+            heuristic,
+            is_goal,
+            Frontier::Heap(BinaryHeap::new()),
+        )
+    }
+
+    fn run<C: Cost, I>(
+        self,
+        mut successors: impl FnMut(&S) -> I,
+        mut heuristic: impl FnMut(&S) -> C,
+        mut is_goal: impl FnMut(&S) -> bool,
+        mut frontier: Frontier<C>,
+    ) -> SearchResult<S, C>
+    where
+        I: IntoIterator<Item = (S, C)>,
+    {
+        let mut result = SearchResult {
+            ids: HashMap::new(),
+            states: vec![],
+            costs: vec![],
+            predecessors: vec![],
+            goals: vec![],
+            cost: None,
+            all_paths: self.all_paths,
+        };
+
+        for start in self.starts {
+            if result.ids.contains_key(&start) {
+                continue;
+            }
+            let priority = heuristic(&start);
+            let id = result.insert(start, C::default());
+            frontier.push(priority, C::default(), id);
+        }
+
+        while let Some((priority, cost, id)) = frontier.pop() {
+            if result.cost.is_some_and(|best| priority > best) {
+                break;
+            }
+            // skip entries superseded by a cheaper path.
+            if cost > result.costs[id] {
+                continue;
+            }
+
+            let state = result.states[id].clone();
+            if is_goal(&state) {
+                result.cost.get_or_insert(cost);
+                result.goals.push(id);
+                continue;
+            }
+
+            for (next, step) in successors(&state) {
+                let next_cost = cost + step;
+                let next_id = match result.ids.get(&next) {
+                    Some(&next_id) => next_id,
+                    None => {
+                        let priority = next_cost + heuristic(&next);
+                        let next_id = result.insert(next, next_cost);
+                        result.predecessors[next_id].push(id);
+                        frontier.push(priority, next_cost, next_id);
+                        continue;
+                    }
+                };
+
+                if next_cost < result.costs[next_id] {
+                    result.costs[next_id] = next_cost;
+                    result.predecessors[next_id] = vec![id];
+                    let priority = next_cost + heuristic(&result.states[next_id]);
+                    frontier.push(priority, next_cost, next_id);
+                } else if next_cost == result.costs[next_id]
+                    && result.all_paths
+                    && !result.predecessors[next_id].contains(&id)
+                {
+                    result.predecessors[next_id].push(id);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+enum Frontier<C> {
+    Queue(VecDeque<(C, usize)>),
+    Heap(BinaryHeap<Reverse<(C, C, usize)>>),
+}
+
+impl<C: Cost> Frontier<C> {
+    fn push(&mut self, priority: C, cost: C, id: usize) {
+        match self {
+            Frontier::Queue(queue) => queue.push_back((cost, id)),
+            Frontier::Heap(heap) => heap.push(Reverse((priority, cost, id))),
+        }
+    }
+
+    /// Returns the next `(priority, cost, id)`.
+    fn pop(&mut self) -> Option<(C, C, usize)> {
+        match self {
+            Frontier::Queue(queue) => queue.pop_front().map(|(cost, id)| (cost, cost, id)),
+            Frontier::Heap(heap) => heap.pop().map(|Reverse(entry)| entry),
+        }
+    }
+}
+
+/// The states reached by a search, with their costs and predecessors.
+pub struct SearchResult<S, C> {
+    ids: HashMap<S, usize>,
+    states: Vec<S>,
+    costs: Vec<C>,
+    predecessors: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+    cost: Option<C>,
+    all_paths: bool,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> SearchResult<S, C> {
+    fn insert(&mut self, state: S, cost: C) -> usize {
+        let id = self.states.len();
+        self.ids.insert(state.clone(), id);
+        self.states.push(state);
+        self.costs.push(cost);
+        self.predecessors.push(vec![]);
+        id
+    }
+
+    /// The cost of the optimal path to a goal, or `None` if no goal is reachable.
+    pub fn cost(&self) -> Option<C> {
+        self.cost
+    }
+
+    /// The goals reached with the optimal cost.
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.goals.iter().map(|&id| &self.states[id])
+    }
+
+    /// The cost of the cheapest path found to `state`. This is optimal for all states whose
+    /// cost does not exceed the optimal cost of a goal; with A*, only for states on optimal paths.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.ids.get(state).map(|&id| self.costs[id])
+    }
+
+    /// Iterates over all states the search reached, with their costs.
+    pub fn reached(&self) -> impl Iterator<Item = (&S, C)> {
+        self.states.iter().zip(self.costs.iter().copied())
+    }
+
+    /// One optimal path from a start to a goal, including both.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goals.first().map(|&id| self.path_from_id(id))
+    }
+
+    /// One cheapest path found from a start to `state`, including both.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.ids.get(state).map(|&id| self.path_from_id(id))
+    }
+
+    fn path_from_id(&self, mut id: usize) -> Vec<S> {
+        let mut path = vec![self.states[id].clone()];
+        while let Some(&previous) = self.predecessors[id].first() {
+            path.push(self.states[previous].clone());
+            id = previous;
+        }
+        path.reverse();
+        path
+    }
+
+    /// The ids of all states on some optimal path to a goal.
+    fn optimal_ids(&self) -> Vec<usize> {
+        assert!(
+            self.all_paths,
+            "optimal paths are only known when searching with `Search::all_paths`"
+        );
+        let mut seen: HashSet<usize> = self.goals.iter().copied().collect();
+        let mut stack = self.goals.clone();
+        while let Some(id) = stack.pop() {
+            for &previous in &self.predecessors[id] {
+                if seen.insert(previous) {
+                    stack.push(previous);
+                }
+            }
+        }
+        seen.into_iter().collect()
+    }
+
+    /// All states on some optimal path from a start to a goal.
+    ///
+    /// Panics unless the search recorded [`all_paths`](Search::all_paths).
+    pub fn on_optimal_paths(&self) -> HashSet<S> {
+        self.optimal_ids()
+            .into_iter()
+            .map(|id| self.states[id].clone())
+            .collect()
+    }
+
+    /// The number of distinct optimal paths from a start to a goal. Edges that cost nothing may
+    /// be counted wrongly.
+    ///
+    /// Panics unless the search recorded [`all_paths`](Search::all_paths).
+    pub fn count_optimal_paths(&self) -> u64 {
+        let mut ids = self.optimal_ids();
+        ids.sort_unstable_by_key(|&id| self.costs[id]);
+
+        // predecessors on an optimal path are always cheaper, so they are counted first.
+        let mut paths: HashMap<usize, u64> = HashMap::new();
+        for id in ids {
+            let count = match self.predecessors[id].as_slice() {
+                [] => 1,
+                predecessors => predecessors.iter().map(|p| paths[p]).sum(),
+            };
+            paths.insert(id, count);
+        }
+        self.goals.iter().map(|id| paths[id]).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A diamond with two equal paths from 0 to 3 and a detour through 4.
+    fn edges(n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 | 2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = Search::new([0])
+            .all_paths()
+            .bfs(|&n| edges(&n).into_iter().map(|(n, _)| n), |&n| n == 3);
+        assert_eq!(result.cost(), Some(2));
+        assert_eq!(result.path(), Some(vec![0, 1, 3]));
+        assert_eq!(result.count_optimal_paths(), 3);
+
+        // without a goal, everything reachable is explored.
+        let result = Search::new([1]).bfs(|&n| edges(&n).into_iter().map(|(n, _)| n), |_| false);
+        assert_eq!(result.cost(), None);
+        assert_eq!(result.reached().count(), 2);
+        assert_eq!(result.distance(&3), Some(1));
+        assert_eq!(result.distance(&0), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = Search::new([0]).all_paths().dijkstra(edges, |&n| n == 3);
+        assert_eq!(result.cost(), Some(2));
+        assert_eq!(result.on_optimal_paths(), HashSet::from([0, 1, 2, 3]));
+        assert_eq!(result.count_optimal_paths(), 2);
+        assert_eq!(result.goals().collect::<Vec<_>>(), vec![&3]);
+
+        let result = Search::new([0]).dijkstra(edges, |&n| n == 5);
+        assert_eq!(result.cost(), None);
+        assert_eq!(result.path(), None);
+    }
+
+    #[test]
+    fn test_multiple_starts_and_goals() {
+        let result = Search::new([1, 2, 4])
+            .all_paths()
+            .dijkstra(edges, |&n| n == 3);
+        assert_eq!(result.cost(), Some(1));
+        assert_eq!(result.count_optimal_paths(), 2);
+        assert!(!result.on_optimal_paths().contains(&4));
+    }
+
+    #[test]
+    fn test_astar() {
+        // walk on a line from 0 to 20, with a heuristic of the remaining distance.
+        let mut expanded = 0;
+        let result = Search::new([0i32]).astar(
+            |&n| {
+                expanded += 1;
+                [(n - 1, 1), (n + 1, 1)]
+            },
+            |&n| (20 - n).unsigned_abs(),
+            |&n| n == 20,
+        );
+        assert_eq!(result.cost(), Some(20));
+        assert_eq!(result.path().unwrap().len(), 21);
+        assert_eq!(expanded, 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "all_paths")]
+    fn test_requires_all_paths() {
+        Search::new([0])
+            .dijkstra(edges, |&n| n == 3)
+            .count_optimal_paths();
+    }
+
+    #[test]
+    fn test_example() {
+        // the cheapest way from 1 to 10, doubling for 1 and adding one for 2
+        let result = Search::new([1]).dijkstra(
+            |&n: &u32| {
+                [(n * 2, 1), (n + 1, 2)]
+                    .into_iter()
+                    .filter(|&(n, _)| n <= 10)
+            },
+            |&n| n == 10,
+        );
+        assert_eq!(result.cost(), Some(5));
+        assert_eq!(result.path(), Some(vec![1, 2, 4, 5, 10]));
+    }
+}
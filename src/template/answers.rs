@@ -0,0 +1,212 @@
+/// Known-good answers, recorded with `cargo all --record` and checked by `cargo all --report`.
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Config, Day};
+
+/// Represents the recorded answers of a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the recorded answers of a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(&Config::get().paths.answers)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns no answers.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(&Config::get().paths.answers)
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Merge two sets of answers, overwriting parts of `self` with the parts of `new` if present.
+    pub fn merge(&self, new: &Self) -> Self {
+        let mut data = self.data.clone();
+
+        for answer in &new.data {
+            match data.iter_mut().find(|a| a.day == answer.day) {
+                Some(existing) => {
+                    if answer.part_1.is_some() {
+                        existing.part_1.clone_from(&answer.part_1);
+                    }
+                    if answer.part_2.is_some() {
+                        existing.part_2.clone_from(&answer.part_2);
+                    }
+                }
+                None => data.push(answer.clone()),
+            }
+        }
+
+        data.sort_unstable_by_key(|a| a.day);
+        Answers { data }
+    }
+
+    /// The recorded answer of a part, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        let part = |p: &Option<String>| p.clone().map_or(JsonValue::Null, JsonValue::String);
+        map.insert("part_1".into(), part(&value.part_1));
+        map.insert("part_2".into(), part(&value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(JsonValue::String(s)) => Ok(Some(s.clone())),
+            Some(_) => Err(format!("Expected answer.{key} to be null or string.")),
+        };
+
+        Ok(Answer {
+            day,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{Answer, Answers};
+
+    #[test]
+    fn roundtrips_json() {
+        let answers = Answers {
+            data: vec![Answer {
+                day: day!(3),
+                part_1: Some("161".into()),
+                part_2: None,
+            }],
+        };
+        let json = tinyjson::JsonValue::from(answers.clone())
+            .stringify()
+            .unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": 11 }] }"#.to_string();
+        assert!(Answers::try_from(json).is_err());
+    }
+
+    #[test]
+    fn merges_parts() {
+        let old = Answers {
+            data: vec![Answer {
+                day: day!(2),
+                part_1: Some("1".into()),
+                part_2: Some("2".into()),
+            }],
+        };
+        let new = Answers {
+            data: vec![
+                Answer {
+                    day: day!(2),
+                    part_1: Some("10".into()),
+                    part_2: None,
+                },
+                Answer {
+                    day: day!(1),
+                    part_1: None,
+                    part_2: Some("5".into()),
+                },
+            ],
+        };
+
+        let merged = old.merge(&new);
+        assert_eq!(merged.data[0].day, day!(1));
+        assert_eq!(merged.get(day!(2), 1), Some("10"));
+        assert_eq!(merged.get(day!(2), 2), Some("2"));
+        assert_eq!(merged.get(day!(1), 1), None);
+    }
+}
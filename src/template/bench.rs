@@ -0,0 +1,165 @@
+/// Support code for the opt-in `cargo bench` harness in `benches/days.rs`.
+///
+/// Every solution registers its steps through the `solution!` macro. The harness benches them with
+/// criterion, and [`read_criterion_timings`] converts the results into [`Timings`] for the readme.
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use std::{env, fs};
+
+use tinyjson::JsonValue;
+
+use crate::template::timings::{Timing, Timings};
+use crate::template::{all_days, Day};
+
+/// A single benchmarked step of a solution, e.g. `parse` or `part_one`.
+pub struct BenchStep {
+    pub name: &'static str,
+    pub run: Box<dyn Fn(&str)>,
+}
+
+/// The benchmarked steps of one day, as registered by the `solution!` macro.
+pub struct DayBench {
+    pub day: Day,
+    pub steps: Vec<BenchStep>,
+}
+
+impl DayBench {
+    pub fn new(day: Day) -> Self {
+        Self { day, steps: vec![] }
+    }
+
+    /// Adds a step. Its return value is passed through [`black_box`] so it is not optimized away.
+    #[must_use]
+    pub fn step<R>(mut self, name: &'static str, func: impl Fn(&str) -> R + 'static) -> Self {
+        self.steps.push(BenchStep {
+            name,
+            run: Box::new(move |input| {
+                black_box(func(black_box(input)));
+            }),
+        });
+        self
+    }
+
+    /// Name of the criterion benchmark group of this day.
+    pub fn group_name(&self) -> String {
+        get_group_name(self.day)
+    }
+}
+
+fn get_group_name(day: Day) -> String {
+    format!("day{day}")
+}
+
+/// The directory criterion writes its results to.
+pub fn get_criterion_dir() -> PathBuf {
+    env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| PathBuf::from("target"), PathBuf::from)
+        .join("criterion")
+}
+
+/// Reads the mean estimate of a single benchmark from criterion's output.
+fn read_estimate(dir: &Path, day: Day, step: &str) -> Option<Duration> {
+    let path = dir
+        .join(get_group_name(day))
+        .join(step)
+        .join("new")
+        .join("estimates.json");
+
+    let json = JsonValue::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let nanos = *json
+        .get::<HashMap<String, JsonValue>>()?
+        .get("mean")?
+        .get::<HashMap<String, JsonValue>>()?
+        .get("point_estimate")?
+        .get::<f64>()?;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some(Duration::from_nanos(nanos as u64))
+}
+
+/// Converts the results of the last `cargo bench` run into [`Timings`].
+/// Days without results for either part are left out.
+pub fn read_criterion_timings(dir: &Path) -> Timings {
+    let data = all_days()
+        .filter_map(|day| {
+            let part_1 = read_estimate(dir, day, "part_one");
+            let part_2 = read_estimate(dir, day, "part_two");
+
+            if part_1.is_none() && part_2.is_none() {
+                return None;
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let total_nanos = [part_1, part_2]
+                .iter()
+                .flatten()
+                .map(|d| d.as_nanos() as f64)
+                .sum();
+
+            Some(Timing {
+                day,
+                part_1: part_1.map(|d| format!("{d:.1?}")),
+                part_2: part_2.map(|d| format!("{d:.1?}")),
+                total_nanos,
+            })
+        })
+        .collect();
+
+    Timings { data }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{read_criterion_timings, DayBench};
+    use crate::day;
+
+    fn write_estimate(dir: &std::path::Path, group: &str, step: &str, mean: f64) {
+        let path = dir.join(group).join(step).join("new");
+        fs::create_dir_all(&path).unwrap();
+        fs::write(
+            path.join("estimates.json"),
+            format!(r#"{{ "mean": {{ "point_estimate": {mean}, "standard_error": 1.0 }} }}"#),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn reads_criterion_estimates() {
+        let dir = std::env::temp_dir().join(format!("aoc-criterion-{}", std::process::id()));
+        write_estimate(&dir, "day01", "part_one", 1500.0);
+        write_estimate(&dir, "day01", "part_two", 2_500_000.0);
+        write_estimate(&dir, "day03", "part_one", 10.0);
+
+        let timings = read_criterion_timings(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(timings.data.len(), 2);
+        assert_eq!(timings.data[0].day, day!(1));
+        assert_eq!(timings.data[0].part_1.as_deref(), Some("1.5µs"));
+        assert_eq!(timings.data[0].part_2.as_deref(), Some("2.5ms"));
+        assert_eq!(timings.data[0].total_nanos, 2_501_500.0);
+        assert_eq!(timings.data[1].day, day!(3));
+        assert_eq!(timings.data[1].part_2, None);
+    }
+
+    #[test]
+    fn registers_steps() {
+        let bench = DayBench::new(day!(5))
+            .step("part_one", |input: &str| input.len())
+            .step("part_two", |_: &str| None::<u32>);
+
+        assert_eq!(bench.group_name(), "day05");
+        assert_eq!(
+            bench.steps.iter().map(|s| s.name).collect::<Vec<_>>(),
+            ["part_one", "part_two"]
+        );
+        (bench.steps[0].run)("input");
+    }
+}
@@ -1,5 +1,65 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::answers::{Answer, Answers};
+use crate::template::day_set::DaySet;
+use crate::template::report::{self, ReportFormat};
+use crate::template::run_multi::{run_multi, DayRun, PartOutcome};
+use crate::template::{all_days, Config};
+
+pub fn handle(is_release: bool, reports: &[ReportFormat], record: bool, days: Option<DaySet>) {
+    let days = days.map_or_else(|| all_days().collect(), |days| days.resolve());
+    let runs = run_multi(&days, is_release, false).days;
+
+    if record {
+        record_answers(&runs);
+    }
+
+    if reports.is_empty() {
+        return;
+    }
+
+    let cases = report::test_cases(&runs, &Answers::read_from_file());
+
+    for format in reports {
+        if let Err(e) = report::write(format, &cases) {
+            eprintln!("Failed to write report: {e}");
+            process::exit(1);
+        }
+    }
+
+    if report::has_failures(&cases) {
+        process::exit(1);
+    }
+}
+
+/// Stores the answers of all solved parts as the expected answers.
+fn record_answers(runs: &[DayRun]) {
+    let answer = |outcome: &PartOutcome| match outcome {
+        PartOutcome::Solved { answer, .. } => Some(answer.clone()),
+        _ => None,
+    };
+
+    let new = Answers {
+        data: runs
+            .iter()
+            .filter_map(|run| {
+                let [part_1, part_2] = run.parts.as_ref()?;
+                Some(Answer {
+                    day: run.day,
+                    part_1: answer(part_1),
+                    part_2: answer(part_2),
+                })
+            })
+            .collect(),
+    };
+
+    let merged = Answers::read_from_file().merge(&new);
+    println!();
+    match merged.store_file() {
+        Ok(()) => println!(
+            "Recorded answers in \"{}\".",
+            Config::get().paths.answers.display()
+        ),
+        Err(e) => eprintln!("Failed to record answers: {e}"),
+    }
 }
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+use std::io::{stdout, Write};
+use std::process::{self, Command, Stdio};
+
+use crate::template::run_multi::child_commands::{parse_part_outcomes, SolutionOutput};
+use crate::template::run_multi::{get_path_for_bin, PartOutcome};
+use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Runs the solution of `day` in `runs` fresh processes and once single-threaded, and reports parts
+/// whose answers differ between runs.
+///
+/// Every process seeds the `RandomState` of `HashMap` and `HashSet` with fresh randomness, so
+/// iteration order bugs show up as differing answers.
+pub fn handle(day: Day, runs: u32) {
+    if !get_path_for_bin(day).exists() {
+        eprintln!("Day {day} has not been scaffolded yet.");
+        process::exit(1);
+    }
+
+    if runs == 0 {
+        eprintln!("--runs must be at least 1.");
+        process::exit(1);
+    }
+
+    build(day);
+
+    println!(
+        "{ANSI_BOLD}Checking day {day} for determinism{ANSI_RESET} {ANSI_ITALIC}({runs} runs + 1 single-threaded){ANSI_RESET}"
+    );
+
+    // answer -> labels of the runs that produced it, per part.
+    let mut answers: [BTreeMap<String, Vec<String>>; 2] = Default::default();
+
+    for run in 0..=runs {
+        let is_single_threaded = run == runs;
+        print!("\rRun {}/{}", run + 1, runs + 1);
+        let _ = stdout().flush();
+
+        let label = if is_single_threaded {
+            "single-threaded".to_string()
+        } else {
+            format!("#{}", run + 1)
+        };
+
+        let outcomes = parse_part_outcomes(&run_solution(day, is_single_threaded));
+        for (part, outcome) in outcomes.iter().enumerate() {
+            answers[part]
+                .entry(format_outcome(outcome))
+                .or_default()
+                .push(label.clone());
+        }
+    }
+
+    print!("\r{}\r", " ".repeat(20));
+
+    let mut is_deterministic = true;
+
+    for (part, answers) in answers.iter().enumerate() {
+        let part = part + 1;
+
+        if answers.len() == 1 {
+            let answer = answers.keys().next().unwrap();
+            println!("Part {part}: {ANSI_BOLD}✔{ANSI_RESET} {answer} (all runs agree)");
+            continue;
+        }
+
+        is_deterministic = false;
+        println!("Part {part}: {ANSI_BOLD}✖ answers differ between runs{ANSI_RESET}");
+        for (answer, labels) in answers {
+            println!(
+                "  {answer} {ANSI_ITALIC}({}){ANSI_RESET}",
+                format_labels(labels)
+            );
+        }
+    }
+
+    if !is_deterministic {
+        process::exit(1);
+    }
+}
+
+fn build(day: Day) {
+    let status = Command::new("cargo")
+        .args(["build", "--quiet", "--release", "--bin", &day.to_string()])
+        .status()
+        .unwrap();
+
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+fn run_solution(day: Day, is_single_threaded: bool) -> SolutionOutput {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--quiet", "--release", "--bin", &day.to_string()])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if is_single_threaded {
+        cmd.env("RAYON_NUM_THREADS", "1");
+    }
+
+    let output = cmd.output().unwrap();
+    let lines = |bytes: &[u8]| {
+        String::from_utf8_lossy(bytes)
+            .lines()
+            .map(String::from)
+            .collect()
+    };
+
+    SolutionOutput {
+        stdout: lines(&output.stdout),
+        stderr: lines(&output.stderr),
+        success: output.status.success(),
+    }
+}
+
+fn format_outcome(outcome: &PartOutcome) -> String {
+    match outcome {
+        PartOutcome::Solved { answer, .. } if answer.contains('\n') => {
+            format!("\n{answer}\n")
+        }
+        PartOutcome::Solved { answer, .. } => answer.clone(),
+        PartOutcome::Unsolved => "not solved".into(),
+        PartOutcome::Skipped => "not run".into(),
+        PartOutcome::Failed(message) => format!("error: {message}"),
+    }
+}
+
+fn format_labels(labels: &[String]) -> String {
+    let runs = if labels.len() == 1 { "run" } else { "runs" };
+    format!("{} {runs}: {}", labels.len(), labels.join(", "))
+}
@@ -0,0 +1,39 @@
+use std::process::{self, Command, Stdio};
+
+use crate::template::Day;
+
+pub fn handle(day: Day, cases: Option<u32>, seed: Option<u64>, size: Option<usize>) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--quiet".to_string(),
+        "--release".to_string(),
+        "--bin".to_string(),
+        day.to_string(),
+        "--".to_string(),
+        "--fuzz".to_string(),
+    ];
+
+    if let Some(cases) = cases {
+        cmd_args.extend(["--cases".to_string(), cases.to_string()]);
+    }
+
+    if let Some(seed) = seed {
+        cmd_args.extend(["--seed".to_string(), seed.to_string()]);
+    }
+
+    if let Some(size) = size {
+        cmd_args.extend(["--size".to_string(), size.to_string()]);
+    }
+
+    let mut cmd = Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    let status = cmd.wait().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
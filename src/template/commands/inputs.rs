@@ -0,0 +1,108 @@
+use std::{fs, process};
+
+use crate::template::inputs::{Bundle, BundleError, Key};
+use crate::template::{Config, Day};
+
+pub enum Action {
+    Encrypt,
+    Decrypt { overwrite: bool },
+}
+
+pub fn handle(action: Action) {
+    let result = match action {
+        Action::Encrypt => encrypt(),
+        Action::Decrypt { overwrite } => decrypt(overwrite),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    }
+}
+
+/// Encrypts all non-empty inputs into the bundle. Inputs that are only in the bundle are kept.
+fn encrypt() -> Result<(), BundleError> {
+    let config = Config::get();
+    let key = Key::load()?;
+    let path = &config.paths.inputs_bundle;
+    let mut bundle = Bundle::load(path)?.unwrap_or_else(|| Bundle::new(&key));
+
+    let mut days = vec![];
+
+    for day in (1..=25).filter_map(Day::new) {
+        let input_path = config.input_path(day);
+        let Ok(input) = fs::read_to_string(&input_path) else {
+            continue;
+        };
+        if input.trim().is_empty() {
+            continue;
+        }
+        bundle.insert(&key, day, &input)?;
+        days.push(day);
+    }
+
+    if days.is_empty() {
+        println!("No inputs found in \"{}\".", config.paths.inputs.display());
+        return Ok(());
+    }
+
+    let io_error = |source| BundleError::Io {
+        path: path.clone(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, bundle.to_string()).map_err(io_error)?;
+
+    println!(
+        "🔒 Encrypted {} inputs to \"{}\" with key {}.",
+        days.len(),
+        path.display(),
+        bundle.fingerprint
+    );
+    Ok(())
+}
+
+/// Writes the inputs of the bundle that are missing or empty, or all of them with `overwrite`.
+fn decrypt(overwrite: bool) -> Result<(), BundleError> {
+    let config = Config::get();
+    let path = &config.paths.inputs_bundle;
+    let Some(bundle) = Bundle::load(path)? else {
+        println!("No inputs bundle found at \"{}\".", path.display());
+        return Ok(());
+    };
+    let key = Key::load()?;
+
+    let mut written = 0;
+    let mut skipped = 0;
+
+    for day in bundle.days() {
+        let input_path = config.input_path(day);
+        let is_empty = fs::read_to_string(&input_path).map_or(true, |s| s.trim().is_empty());
+        if !is_empty && !overwrite {
+            skipped += 1;
+            continue;
+        }
+
+        let input = bundle.decrypt(&key, day)?.unwrap_or_default();
+        let io_error = |source| BundleError::Io {
+            path: input_path.clone(),
+            source,
+        };
+        if let Some(parent) = input_path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(&input_path, input).map_err(io_error)?;
+        written += 1;
+    }
+
+    println!(
+        "🔓 Decrypted {written} inputs to \"{}\".",
+        config.paths.inputs.display()
+    );
+    if skipped > 0 {
+        println!("Kept {skipped} existing inputs. Pass --overwrite to replace them.");
+    }
+    Ok(())
+}
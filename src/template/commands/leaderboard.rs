@@ -0,0 +1,147 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::time::{Duration, SystemTime};
+use std::{env, io};
+
+use crate::template::leaderboard::{format_day, format_ranking, Leaderboard};
+use crate::template::{Config, Day};
+
+/// The website asks to not request the leaderboard more often than every 15 minutes.
+const FETCH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+pub fn handle(file: Option<PathBuf>, fetch: bool, day: Option<Day>) {
+    let path = match (file, fetch) {
+        (Some(file), false) => file,
+        (None, true) => fetch_cached(),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: cargo leaderboard <file> or cargo leaderboard --fetch");
+            process::exit(1);
+        }
+    };
+
+    let leaderboard = fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|s| s.parse::<Leaderboard>())
+        .unwrap_or_else(|e| {
+            eprintln!("Could not read leaderboard \"{}\": {e}", path.display());
+            process::exit(1);
+        });
+
+    print!("{}", format_ranking(&leaderboard));
+
+    let days = match day {
+        Some(day) => vec![day],
+        None => leaderboard.days(),
+    };
+
+    for day in days {
+        println!();
+        print!("{}", format_day(&leaderboard, day));
+    }
+}
+
+fn get_cache_path() -> PathBuf {
+    Config::get().paths.data.join("leaderboard.json")
+}
+
+/// Downloads the leaderboard of `leaderboard_id` unless the cached copy is recent enough.
+fn fetch_cached() -> PathBuf {
+    let config = Config::get();
+    let path = get_cache_path();
+
+    let is_fresh = fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < FETCH_INTERVAL);
+
+    if is_fresh {
+        println!("Using leaderboard fetched less than 15 minutes ago.\n");
+        return path;
+    }
+
+    let (Some(year), Some(id)) = (config.year(), config.leaderboard_id) else {
+        eprintln!("Set `year` and `leaderboard_id` in aoc.toml to fetch the leaderboard.");
+        process::exit(1);
+    };
+
+    let session = read_session().unwrap_or_else(|e| {
+        eprintln!("Could not read session cookie: {e}");
+        process::exit(1);
+    });
+
+    let url = format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json");
+    if let Err(e) = download(&url, &session, &path) {
+        eprintln!("Failed to fetch leaderboard: {e}");
+        process::exit(1);
+    }
+
+    path
+}
+
+/// Reads the session cookie the same way as aoc-cli does.
+fn read_session() -> io::Result<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = match &Config::get().paths.session_file {
+        Some(path) => path.clone(),
+        None => env::var("HOME")
+            .map(|home| Path::new(&home).join(".adventofcode.session"))
+            .map_err(|_| io::Error::new(io::ErrorKind::NotFound, "HOME is not set"))?,
+    };
+
+    Ok(fs::read_to_string(path)?.trim().to_string())
+}
+
+/// Identifies this project to the website, by its repository if `Cargo.toml` sets one.
+fn user_agent() -> String {
+    let contact = match env!("CARGO_PKG_REPOSITORY") {
+        "" => env!("CARGO_PKG_AUTHORS"),
+        repository => repository,
+    };
+    format!(
+        "{}/{} (leaderboard command; {contact})",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+fn download(url: &str, session: &str, path: &Path) -> io::Result<()> {
+    // the cookie header is passed on stdin, as arguments are visible to other users in `ps`.
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location"])
+        .args(["--header", "@-"])
+        .args(["--user-agent", &user_agent()])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "Cookie: session={session}")?;
+    }
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    // the website redirects to the login page if the session is invalid.
+    if !output.stdout.starts_with(b"{") {
+        return Err(io::Error::other(
+            "the response is not JSON, the session cookie may have expired.",
+        ));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, output.stdout)
+}
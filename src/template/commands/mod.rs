@@ -1,6 +1,13 @@
 pub mod all;
+pub mod determinism;
 pub mod download;
+pub mod fuzz;
+pub mod inputs;
+pub mod leaderboard;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod splits;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -1,14 +1,62 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::puzzle::{render, split_parts, terminal_width};
+use crate::template::splits::{log_event, EventKind};
+use crate::template::{aoc_cli, Config, Day};
 
-pub fn handle(day: Day) {
+/// Prints the cached puzzle description, fetching it with aoc-cli if it is missing or `refresh` is set.
+pub fn handle(day: Day, refresh: bool, part: Option<u8>) {
+    if part.is_some_and(|p| p != 1 && p != 2) {
+        eprintln!("Unexpected command-line input. Format: cargo read 1 --part 2");
+        process::exit(1);
+    }
+
+    let path = Config::get().puzzle_path(day);
+    let mut is_fetched = false;
+
+    if refresh || !path.exists() {
+        fetch(day);
+        is_fetched = true;
+    }
+
+    let mut markdown = read_puzzle(day);
+
+    // part two unlocks after solving part one, so a cached description may be outdated.
+    if part == Some(2) && split_parts(&markdown).1.is_none() && !is_fetched {
+        fetch(day);
+        markdown = read_puzzle(day);
+    }
+
+    let (part_one, part_two) = split_parts(&markdown);
+
+    let selected = match part {
+        Some(1) => part_one,
+        Some(_) => part_two.unwrap_or_else(|| {
+            eprintln!("Part two of day {day} has not been unlocked yet. Solve part one first.");
+            process::exit(1);
+        }),
+        None => &markdown,
+    };
+
+    print!("{}", render(selected, terminal_width()));
+    log_event(day, EventKind::Read);
+}
+
+fn read_puzzle(day: Day) -> String {
+    let path = Config::get().puzzle_path(day);
+    fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("Could not read puzzle \"{}\": {e}", path.display());
+        process::exit(1);
+    })
+}
+
+fn fetch(day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if let Err(e) = aoc_cli::download_puzzle(day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
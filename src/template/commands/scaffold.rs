@@ -1,15 +1,21 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-use crate::template::Day;
+use crate::template::splits::{log_event, EventKind};
+use crate::template::{Config, Day};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+/// The binary only hands the library module of the day to the `solution!` macro.
+const BIN_TEMPLATE: &str =
+    "use advent_of_code::days::day%DAY_PADDED%::*;\n\nadvent_of_code::solution!(%DAY_NUMBER%);\n";
+
+fn safe_create_file(path: &Path, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
         file.create(true);
@@ -19,7 +25,59 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn write_template(path: &Path, template: &str, day: Day, overwrite: bool) {
+    let mut file = match safe_create_file(path, overwrite) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create module file \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    };
+
+    let contents = template
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%DAY_PADDED%", &day.to_string());
+
+    match file.write_all(contents.as_bytes()) {
+        Ok(()) => {
+            println!("Created module file \"{}\"", path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to write module contents: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn get_days_mod_path() -> PathBuf {
+    Path::new("src").join("days").join("mod.rs")
+}
+
+/// Declares `pub mod dayNN;` in `src/days/mod.rs`, keeping the declarations sorted.
+/// Returns `false` if the module was declared already.
+fn register_module(day: Day) -> io::Result<bool> {
+    let path = get_days_mod_path();
+    let contents = fs::read_to_string(&path)?;
+    let declaration = format!("pub mod day{day};");
+
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.iter().any(|line| line.trim() == declaration) {
+        return Ok(false);
+    }
+
+    let is_declaration = |line: &&str| line.starts_with("pub mod day");
+    let index = lines
+        .iter()
+        .position(|line| is_declaration(line) && *line > declaration.as_str())
+        .or_else(|| lines.iter().rposition(is_declaration).map(|i| i + 1))
+        .unwrap_or(lines.len());
+
+    lines.insert(index, &declaration);
+    fs::write(&path, lines.join("\n") + "\n")?;
+    Ok(true)
+}
+
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -28,35 +86,32 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let config = Config::get();
+    let input_path = config.input_path(day);
+    let example_path = config.example_path(day);
+    let module_path = config.day_module_path(day);
+    let bin_path = config.solution_path(day);
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
+    write_template(&module_path, MODULE_TEMPLATE, day, overwrite);
+    write_template(&bin_path, BIN_TEMPLATE, day, overwrite);
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+    match register_module(day) {
+        Ok(true) => {
+            println!(
+                "Declared module \"day{day}\" in \"{}\"",
+                get_days_mod_path().display()
+            );
         }
+        Ok(false) => {}
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to declare module: {e}");
             process::exit(1);
         }
     }
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -66,7 +121,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -74,6 +129,8 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    log_event(day, EventKind::Scaffold);
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
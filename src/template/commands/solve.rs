@@ -1,8 +1,17 @@
 use std::process::{Command, Stdio};
 
+use crate::template::visualize::Settings;
 use crate::template::Day;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    variant: Option<String>,
+    visualize: Option<Settings>,
+    verbosity: u8,
+) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -16,6 +25,10 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push("--release".to_string());
     }
 
+    if visualize.is_some() {
+        cmd_args.extend(["--features".to_string(), "visualize".to_string()]);
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -23,6 +36,24 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(variant) = variant {
+        cmd_args.push("--variant".to_string());
+        cmd_args.push(variant);
+    }
+
+    if verbosity > 0 {
+        cmd_args.push(format!("-{}", "v".repeat(verbosity.min(2).into())));
+    }
+
+    if let Some(settings) = visualize {
+        cmd_args.extend([
+            "--visualize".to_string(),
+            settings.output.to_string(),
+            "--fps".to_string(),
+            settings.fps.to_string(),
+        ]);
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -0,0 +1,28 @@
+use std::{fs, io, process};
+
+use crate::template::splits::{format_report, parse_events};
+use crate::template::Config;
+
+pub fn handle() {
+    let config = Config::get();
+    let path = &config.paths.events;
+
+    let events = match fs::read_to_string(path) {
+        Ok(s) => parse_events(&s).unwrap_or_else(|e| {
+            eprintln!("Could not read event log \"{}\": {e}", path.display());
+            process::exit(1);
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => vec![],
+        Err(e) => {
+            eprintln!("Could not read event log \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    };
+
+    if events.is_empty() {
+        println!("No events recorded yet. Events are logged by `cargo scaffold`, `cargo read` and `cargo solve --submit`.");
+        return;
+    }
+
+    print!("{}", format_report(&events, config.year()));
+}
@@ -1,13 +1,62 @@
 use std::collections::HashSet;
+use std::process::{self, Command, Stdio};
 
-use crate::template::run_multi::run_multi;
+use crate::template::bench::{get_criterion_dir, read_criterion_timings};
+use crate::template::day_set::DaySet;
+use crate::template::profiles::{format_matrix, Profile};
+use crate::template::run_multi::child_commands::{parse_exec_time, run_solution_with_profile};
+use crate::template::run_multi::{get_path_for_bin, run_multi};
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, readme_benchmarks, Day, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(
+    days: Option<DaySet>,
+    run_all: bool,
+    store: bool,
+    from_bench: bool,
+    variants: bool,
+    profiles: &[Profile],
+) {
+    if !profiles.is_empty() {
+        compare_profiles(days.as_ref(), profiles);
+        return;
+    }
+
+    if variants {
+        let Some(day) = days.as_ref().and_then(DaySet::as_single) else {
+            eprintln!("Unexpected command-line input. Format: cargo time 1 --variants");
+            process::exit(1);
+        };
+        compare_variants(day);
+        return;
+    }
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    let timings = if from_bench {
+        import_bench_timings(days.as_ref())
+    } else {
+        run_timings(days.as_ref(), run_all, &stored_timings)
+    };
+
+    if store {
+        let merged_timings = stored_timings.merge(&timings);
+        merged_timings.store_file().unwrap();
+
+        println!();
+        match readme_benchmarks::update(merged_timings) {
+            Ok(()) => {
+                println!("Stored updated benchmarks.");
+            }
+            Err(_) => {
+                eprintln!("Failed to store updated benchmarks.");
+            }
+        }
+    }
+}
+
+fn run_timings(days: Option<&DaySet>, run_all: bool, stored_timings: &Timings) -> Timings {
+    let days_to_run: HashSet<Day> = days.map_or_else(
         || {
             if run_all {
                 all_days().collect()
@@ -18,23 +67,92 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        DaySet::resolve,
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    run_multi(&days_to_run, true, true)
+        .timings
+        .unwrap_or_default()
+}
 
-    if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+/// Reads the results of the last `cargo bench --features bench` run instead of benching.
+fn import_bench_timings(days: Option<&DaySet>) -> Timings {
+    let dir = get_criterion_dir();
+    let mut timings = read_criterion_timings(&dir);
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+    if let Some(days) = days.map(DaySet::resolve) {
+        timings.data.retain(|t| days.contains(&t.day));
+    }
+
+    if timings.data.is_empty() {
+        eprintln!(
+            "No benchmark results found in \"{}\". Run `cargo bench --features bench` first.",
+            dir.display()
+        );
+    }
+
+    for timing in &timings.data {
+        println!(
+            "{ANSI_BOLD}Day {}{ANSI_RESET}: {} / {}",
+            timing.day,
+            timing.part_1.as_deref().unwrap_or("-"),
+            timing.part_2.as_deref().unwrap_or("-"),
+        );
+    }
+
+    timings
+}
+
+/// Benches all registered variants of `day` against each other. The results are not stored.
+fn compare_variants(day: Day) {
+    let status = Command::new("cargo")
+        .args(["run", "--quiet", "--release", "--bin", &day.to_string()])
+        .args(["--", "--variants"])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .unwrap();
+
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+/// Benches the solved days (or `days`) under each profile and prints the timings side by side.
+/// The results are not stored.
+fn compare_profiles(days: Option<&DaySet>, profiles: &[Profile]) {
+    let selected = days.map(DaySet::resolve);
+    let days: Vec<Day> = all_days()
+        .filter(|d| selected.as_ref().is_none_or(|days| days.contains(d)))
+        .filter(|d| get_path_for_bin(*d).exists())
+        .collect();
+
+    let mut results = vec![];
+
+    for profile in profiles {
+        println!("{ANSI_BOLD}Profile {profile}{ANSI_RESET}");
+        println!("------");
+
+        match profile.build() {
+            Ok(status) if status.success() => {}
+            _ => {
+                eprintln!("Failed to build profile `{profile}`. Profiles other than `dev` and `release` need to be defined in Cargo.toml.");
+                process::exit(1);
             }
         }
+
+        let data = days
+            .iter()
+            .map(|day| {
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+                let output = run_solution_with_profile(*day, true, profile).unwrap();
+                parse_exec_time(&output.stdout, *day)
+            })
+            .collect();
+
+        results.push((profile.clone(), Timings { data }));
+        println!();
     }
+
+    print!("{}", format_matrix(&results));
 }
@@ -0,0 +1,106 @@
+use std::io::{stdout, Write};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::input_check::{check_input, InputIssue};
+use crate::template::leaderboard::format_delta;
+use crate::template::{aoc_cli, Config, Day, ANSI_BOLD, ANSI_RESET};
+
+/// The input endpoint may answer with a 404 for a few seconds after the puzzle unlocked.
+const DOWNLOAD_ATTEMPTS: u32 = 10;
+const DOWNLOAD_RETRY_DELAY: Duration = Duration::from_secs(3);
+
+pub fn handle(wait: bool) {
+    if wait {
+        wait_for_unlock();
+        return;
+    }
+
+    match Day::today() {
+        Some(day) => {
+            scaffold::handle(day, false);
+            download::handle(day);
+            read::handle(day, false, None);
+        }
+        None => {
+            eprintln!(
+                "`today` command can only be run between the 1st and \
+                the 25th of december. Please use `scaffold` with a specific day \
+                or `today --wait` to wait for the next puzzle."
+            );
+            process::exit(1)
+        }
+    };
+}
+
+/// Counts down to the next unlock, then scaffolds, downloads and reads the puzzle.
+fn wait_for_unlock() {
+    let Some((day, unlock)) = Day::next_unlock(Utc::now()) else {
+        eprintln!("Could not determine the next puzzle unlock.");
+        process::exit(1);
+    };
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    if let Some(year) = Config::get().year() {
+        if i32::from(year) != chrono::Datelike::year(&unlock) {
+            eprintln!(
+                "Warning: `year` is set to {year}, the puzzle will be downloaded for that year."
+            );
+        }
+    }
+
+    println!("{ANSI_BOLD}Waiting for day {day}{ANSI_RESET}, press Ctrl+C to cancel.");
+
+    loop {
+        let remaining = (unlock.with_timezone(&Utc) - Utc::now()).num_seconds();
+        if remaining <= 0 {
+            break;
+        }
+
+        print!("\r⏳ Unlocks in {}   ", format_delta(remaining));
+        let _ = stdout().flush();
+        thread::sleep(Duration::from_millis(250));
+    }
+
+    println!("\r🎄 Day {day} is unlocked!{}", " ".repeat(20));
+
+    if Config::get().solution_path(day).exists() {
+        println!("Solution for day {day} exists already, skipping scaffold.");
+    } else {
+        scaffold::handle(day, false);
+    }
+
+    download_with_retry(day);
+    read::handle(day, false, None);
+}
+
+fn download_with_retry(day: Day) {
+    for attempt in 1..=DOWNLOAD_ATTEMPTS {
+        let is_downloaded = aoc_cli::download(day).is_ok()
+            && std::fs::read_to_string(Config::get().input_path(day))
+                .is_ok_and(|input| !check_input(&input).contains(&InputIssue::ErrorPage));
+
+        if is_downloaded {
+            return;
+        }
+
+        if attempt < DOWNLOAD_ATTEMPTS {
+            println!(
+                "Puzzle is not available yet, retrying in {}s ({attempt}/{DOWNLOAD_ATTEMPTS})...",
+                DOWNLOAD_RETRY_DELAY.as_secs()
+            );
+            thread::sleep(DOWNLOAD_RETRY_DELAY);
+        }
+    }
+
+    eprintln!("Failed to download day {day}. Try again with `cargo download {day}`.");
+    process::exit(1);
+}
@@ -0,0 +1,478 @@
+/// Project configuration, read from `aoc.toml` in the repository root.
+///
+/// Every key is optional. A missing file is equivalent to an empty one.
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::{env, fs, process};
+
+use toml::{Table, Value};
+
+use crate::template::Day;
+
+pub static CONFIG_FILE_PATH: &str = "aoc.toml";
+
+/// Typed view of `aoc.toml`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// The event year. Falls back to the `AOC_YEAR` environment variable.
+    pub year: Option<u16>,
+    /// The id of the private leaderboard read by `cargo leaderboard --fetch`.
+    pub leaderboard_id: Option<u64>,
+    pub paths: Paths,
+    pub bench: Bench,
+    pub defaults: Defaults,
+}
+
+/// The `[paths]` table. Paths are used as written, so relative paths are relative to the working
+/// directory, which is the repository root when running the cargo aliases from there.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Paths {
+    pub data: PathBuf,
+    pub inputs: PathBuf,
+    pub examples: PathBuf,
+    pub puzzles: PathBuf,
+    pub readme: PathBuf,
+    pub timings: PathBuf,
+    pub answers: PathBuf,
+    /// Log of scaffold, read and submit events, read by `cargo splits`.
+    pub events: PathBuf,
+    /// Encrypted inputs written by `cargo inputs encrypt`, read when an input file is missing.
+    pub inputs_bundle: PathBuf,
+    /// File holding the key of the inputs bundle, if `AOC_INPUTS_KEY` is not set.
+    pub inputs_key: PathBuf,
+    /// Directory that `cargo solve --visualize gif|png|ppm` writes images to.
+    pub visualizations: PathBuf,
+    /// Passed to aoc-cli as `--session-file` when set.
+    pub session_file: Option<PathBuf>,
+}
+
+/// The `[bench]` table, controlling how long `cargo time` benches each part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bench {
+    pub budget: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+/// The `[defaults]` table, holding default values for command-line flags.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Defaults {
+    pub release: bool,
+    pub store: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            leaderboard_id: None,
+            paths: Paths::with_data_dir(PathBuf::from("data")),
+            bench: Bench::default(),
+            defaults: Defaults::default(),
+        }
+    }
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl Paths {
+    fn with_data_dir(data: PathBuf) -> Self {
+        Self {
+            inputs: data.join("inputs"),
+            examples: data.join("examples"),
+            puzzles: data.join("puzzles"),
+            timings: data.join("timings.json"),
+            answers: data.join("answers.json"),
+            events: data.join("events.log"),
+            inputs_bundle: data.join("inputs.enc"),
+            visualizations: data.join("visualizations"),
+            data,
+            readme: PathBuf::from("README.md"),
+            inputs_key: PathBuf::from(".aoc-inputs-key"),
+            session_file: None,
+        }
+    }
+}
+
+impl Config {
+    /// Returns the configuration of the current project, loading it on first access.
+    /// Exits the process with an error message if `aoc.toml` is invalid.
+    pub fn get() -> &'static Config {
+        static CONFIG: OnceLock<Config> = OnceLock::new();
+        CONFIG.get_or_init(|| match Self::load(Path::new(CONFIG_FILE_PATH)) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        })
+    }
+
+    /// Reads the configuration from a file. If not present, returns the default configuration.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(ConfigError::new("", e.to_string())),
+        }
+    }
+
+    /// Parses and validates the contents of an `aoc.toml` file.
+    pub fn parse(s: &str) -> Result<Self, ConfigError> {
+        let table: Table = s
+            .parse()
+            .map_err(|e: toml::de::Error| ConfigError::new("", e.to_string().trim()))?;
+
+        check_keys(
+            &table,
+            "",
+            &["year", "leaderboard_id", "paths", "bench", "defaults"],
+        )?;
+
+        let year = optional(&table, "", "year", |v| {
+            v.as_integer()
+                .and_then(|x| u16::try_from(x).ok())
+                .filter(|x| *x >= 2015)
+                .ok_or("expected a year of 2015 or later")
+        })?;
+
+        let leaderboard_id = optional(&table, "", "leaderboard_id", |v| {
+            v.as_integer()
+                .and_then(|x| u64::try_from(x).ok())
+                .ok_or("expected a positive integer")
+        })?;
+
+        let paths = match sub_table(&table, "paths")? {
+            Some(t) => parse_paths(t)?,
+            None => Paths::with_data_dir(PathBuf::from("data")),
+        };
+
+        let bench = match sub_table(&table, "bench")? {
+            Some(t) => parse_bench(t)?,
+            None => Bench::default(),
+        };
+
+        let defaults = match sub_table(&table, "defaults")? {
+            Some(t) => parse_defaults(t)?,
+            None => Defaults::default(),
+        };
+
+        Ok(Self {
+            year,
+            leaderboard_id,
+            paths,
+            bench,
+            defaults,
+        })
+    }
+
+    /// The event year, read from `aoc.toml` or the `AOC_YEAR` environment variable.
+    pub fn year(&self) -> Option<u16> {
+        self.year
+            .or_else(|| env::var("AOC_YEAR").ok().and_then(|x| x.parse().ok()))
+    }
+
+    /// Resolves a data folder name as used by `read_file`, e.g. `inputs` or `examples`.
+    pub fn data_dir(&self, folder: &str) -> PathBuf {
+        match folder {
+            "inputs" => self.paths.inputs.clone(),
+            "examples" => self.paths.examples.clone(),
+            "puzzles" => self.paths.puzzles.clone(),
+            folder => self.paths.data.join(folder),
+        }
+    }
+
+    pub fn input_path(&self, day: Day) -> PathBuf {
+        self.paths.inputs.join(format!("{day}.txt"))
+    }
+
+    pub fn example_path(&self, day: Day) -> PathBuf {
+        self.paths.examples.join(format!("{day}.txt"))
+    }
+
+    pub fn puzzle_path(&self, day: Day) -> PathBuf {
+        self.paths.puzzles.join(format!("{day}.md"))
+    }
+
+    /// The binary of a day, e.g. `src/bin/01.rs`. Not configurable, as cargo only discovers
+    /// binaries in `src/bin`.
+    pub fn solution_path(&self, day: Day) -> PathBuf {
+        PathBuf::from("src").join("bin").join(format!("{day}.rs"))
+    }
+
+    /// The library module of a day, e.g. `src/days/day01.rs`. Not configurable, as the module
+    /// is declared in `src/days/mod.rs`.
+    pub fn day_module_path(&self, day: Day) -> PathBuf {
+        PathBuf::from("src")
+            .join("days")
+            .join(format!("day{day}.rs"))
+    }
+}
+
+fn parse_paths(table: &Table) -> Result<Paths, ConfigError> {
+    check_keys(
+        table,
+        "paths",
+        &[
+            "data",
+            "inputs",
+            "examples",
+            "puzzles",
+            "readme",
+            "timings",
+            "answers",
+            "events",
+            "inputs_bundle",
+            "inputs_key",
+            "visualizations",
+            "session_file",
+        ],
+    )?;
+
+    let path = |key: &str| optional(table, "paths", key, as_path);
+
+    let data = path("data")?.unwrap_or_else(|| PathBuf::from("data"));
+    let mut paths = Paths::with_data_dir(data);
+
+    if let Some(p) = path("inputs")? {
+        paths.inputs = p;
+    }
+    if let Some(p) = path("examples")? {
+        paths.examples = p;
+    }
+    if let Some(p) = path("puzzles")? {
+        paths.puzzles = p;
+    }
+    if let Some(p) = path("readme")? {
+        paths.readme = p;
+    }
+    if let Some(p) = path("timings")? {
+        paths.timings = p;
+    }
+    if let Some(p) = path("answers")? {
+        paths.answers = p;
+    }
+    if let Some(p) = path("events")? {
+        paths.events = p;
+    }
+    if let Some(p) = path("inputs_bundle")? {
+        paths.inputs_bundle = p;
+    }
+    if let Some(p) = path("inputs_key")? {
+        paths.inputs_key = p;
+    }
+    if let Some(p) = path("visualizations")? {
+        paths.visualizations = p;
+    }
+    paths.session_file = path("session_file")?;
+
+    Ok(paths)
+}
+
+fn parse_bench(table: &Table) -> Result<Bench, ConfigError> {
+    check_keys(table, "bench", &["budget_ms", "min_samples", "max_samples"])?;
+
+    let positive = |key: &str| {
+        optional(table, "bench", key, |v| {
+            v.as_integer()
+                .and_then(|x| u64::try_from(x).ok())
+                .filter(|x| *x > 0)
+                .ok_or("expected a positive integer")
+        })
+    };
+
+    let mut bench = Bench::default();
+    if let Some(ms) = positive("budget_ms")? {
+        bench.budget = Duration::from_millis(ms);
+    }
+    if let Some(n) = positive("min_samples")? {
+        bench.min_samples = n.into();
+    }
+    if let Some(n) = positive("max_samples")? {
+        bench.max_samples = n.into();
+    }
+
+    if bench.max_samples < bench.min_samples {
+        return Err(ConfigError::new(
+            "bench.max_samples",
+            format!(
+                "must not be lower than `bench.min_samples` ({})",
+                bench.min_samples
+            ),
+        ));
+    }
+
+    Ok(bench)
+}
+
+fn parse_defaults(table: &Table) -> Result<Defaults, ConfigError> {
+    check_keys(table, "defaults", &["release", "store"])?;
+
+    let flag = |key: &str| {
+        optional(table, "defaults", key, |v| {
+            v.as_bool().ok_or("expected `true` or `false`")
+        })
+        .map(Option::unwrap_or_default)
+    };
+
+    Ok(Defaults {
+        release: flag("release")?,
+        store: flag("store")?,
+    })
+}
+
+fn as_path(value: &Value) -> Result<PathBuf, &'static str> {
+    let s = value.as_str().ok_or("expected a string")?;
+    if s.trim().is_empty() {
+        return Err("expected a non-empty path");
+    }
+    match s.strip_prefix("~/") {
+        Some(rest) => env::var("HOME")
+            .map(|home| PathBuf::from(home).join(rest))
+            .map_err(|_| "could not expand `~`, HOME is not set"),
+        None => Ok(PathBuf::from(s)),
+    }
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+fn check_keys(table: &Table, prefix: &str, allowed: &[&str]) -> Result<(), ConfigError> {
+    let allowed: HashSet<&str> = allowed.iter().copied().collect();
+    match table.keys().find(|k| !allowed.contains(k.as_str())) {
+        Some(key) => Err(ConfigError::new(&join_key(prefix, key), "unknown key")),
+        None => Ok(()),
+    }
+}
+
+fn sub_table<'a>(table: &'a Table, key: &str) -> Result<Option<&'a Table>, ConfigError> {
+    optional(table, "", key, |v| v.as_table().ok_or("expected a table"))
+}
+
+fn optional<'a, T, E: Display>(
+    table: &'a Table,
+    prefix: &str,
+    key: &str,
+    convert: impl Fn(&'a Value) -> Result<T, E>,
+) -> Result<Option<T>, ConfigError> {
+    table
+        .get(key)
+        .map(|v| convert(v).map_err(|e| ConfigError::new(&join_key(prefix, key), e)))
+        .transpose()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An invalid `aoc.toml`, naming the offending key if the file could be parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConfigError {
+    pub key: String,
+    pub message: String,
+}
+
+impl ConfigError {
+    fn new(key: &str, message: impl Display) -> Self {
+        Self {
+            key: key.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.key.is_empty() {
+            write!(f, "{CONFIG_FILE_PATH}: {}", self.message)
+        } else {
+            write!(f, "{CONFIG_FILE_PATH}: `{}`: {}", self.key, self.message)
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::Config;
+
+    #[test]
+    fn handles_empty_config() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn handles_full_config() {
+        let config = Config::parse(
+            r#"
+            year = 2023
+
+            [paths]
+            data = "puzzle-data"
+            readme = "docs/README.md"
+
+            [bench]
+            budget_ms = 250
+            max_samples = 500
+
+            [defaults]
+            release = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.paths.inputs, PathBuf::from("puzzle-data/inputs"));
+        assert_eq!(
+            config.paths.timings,
+            PathBuf::from("puzzle-data/timings.json")
+        );
+        assert_eq!(config.paths.readme, PathBuf::from("docs/README.md"));
+        assert_eq!(config.bench.budget, Duration::from_millis(250));
+        assert_eq!(config.bench.min_samples, 10);
+        assert_eq!(config.bench.max_samples, 500);
+        assert!(config.defaults.release);
+        assert!(!config.defaults.store);
+    }
+
+    #[test]
+    fn names_offending_keys() {
+        let key = |s: &str| Config::parse(s).unwrap_err().key;
+        assert_eq!(key("year = \"2024\""), "year");
+        assert_eq!(key("[paths]\ninputs = 3"), "paths.inputs");
+        assert_eq!(key("[paths]\nsolutions = \"bin\""), "paths.solutions");
+        assert_eq!(key("[bench]\nbudget_ms = -1"), "bench.budget_ms");
+        assert_eq!(
+            key("[bench]\nmin_samples = 20\nmax_samples = 5"),
+            "bench.max_samples"
+        );
+        assert_eq!(key("[defaults]\nrelase = true"), "defaults.relase");
+        assert_eq!(key("paths = 1"), "paths");
+    }
+
+    #[test]
+    fn reports_syntax_errors() {
+        let err = Config::parse("year = ").unwrap_err();
+        assert_eq!(err.key, "");
+        assert!(err.to_string().starts_with("aoc.toml: "));
+    }
+}
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, Days, FixedOffset, NaiveDate, TimeZone, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
@@ -41,6 +41,22 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns the unix timestamp at which the puzzle of this day unlocked in `year`,
+    /// i.e. midnight in the server's UTC-5 offset.
+    pub fn unlock_ts(self, year: u16) -> i64 {
+        days_from_civil(i64::from(year), 12, i64::from(self.0)) * 86400 + 5 * 3600
+    }
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
 }
 
 #[cfg(feature = "today")]
@@ -55,6 +71,28 @@ impl Day {
             None
         }
     }
+
+    /// Returns the next puzzle to unlock after `now`, together with its unlock time.
+    pub fn next_unlock(now: DateTime<Utc>) -> Option<(Self, DateTime<FixedOffset>)> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let tomorrow = now
+            .with_timezone(&offset)
+            .date_naive()
+            .checked_add_days(Days::new(1))?;
+
+        let date = if tomorrow.month() == 12 && tomorrow.day() <= 25 {
+            tomorrow
+        } else if tomorrow.month() == 12 {
+            NaiveDate::from_ymd_opt(tomorrow.year() + 1, 12, 1)?
+        } else {
+            NaiveDate::from_ymd_opt(tomorrow.year(), 12, 1)?
+        };
+
+        let unlock = offset
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+            .single()?;
+        Some((Self::new(u8::try_from(date.day()).ok()?)?, unlock))
+    }
 }
 
 impl Display for Day {
@@ -187,6 +225,36 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[cfg(feature = "today")]
+    #[test]
+    fn next_unlock() {
+        use chrono::{TimeZone, Utc};
+
+        let unlock = |y, m, d, h, min| {
+            let (day, time) =
+                Day::next_unlock(Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()).unwrap();
+            (day.into_inner(), time.to_rfc3339())
+        };
+
+        // 04:59 UTC on the 5th is still the 4th on the server.
+        assert_eq!(
+            unlock(2024, 12, 5, 4, 59),
+            (5, "2024-12-05T00:00:00-05:00".into())
+        );
+        assert_eq!(
+            unlock(2024, 12, 5, 5, 0),
+            (6, "2024-12-06T00:00:00-05:00".into())
+        );
+        assert_eq!(
+            unlock(2024, 11, 3, 12, 0),
+            (1, "2024-12-01T00:00:00-05:00".into())
+        );
+        assert_eq!(
+            unlock(2024, 12, 25, 12, 0),
+            (1, "2025-12-01T00:00:00-05:00".into())
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
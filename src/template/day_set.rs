@@ -0,0 +1,291 @@
+/// Day-set expressions selecting the days run by `cargo all` and `cargo time`.
+///
+/// An expression is a comma-separated list of terms:
+///  - `7` or `1-10` select single days or ranges,
+///  - `unsolved` selects scaffolded days without both recorded answers in `answers.json`,
+///  - `unbenched` selects days without both timings in `timings.json`,
+///  - `slowest:5` selects the five slowest days in `timings.json`,
+///  - `all` selects every day.
+///
+/// Terms prefixed with `!` are removed from the selection, e.g. `1-10,!5`. An expression of only
+/// exclusions starts from all days.
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::answers::Answers;
+use crate::template::run_multi::get_path_for_bin;
+use crate::template::timings::Timings;
+use crate::template::{all_days, Day};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Term {
+    Range(Day, Day),
+    Unsolved,
+    Unbenched,
+    Slowest(usize),
+    All,
+}
+
+impl FromStr for Term {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |s: &str| {
+            s.trim()
+                .parse::<Day>()
+                .map_err(|e| format!("`{}`: {e}.", s.trim()))
+        };
+
+        match s {
+            "unsolved" => Ok(Term::Unsolved),
+            "unbenched" => Ok(Term::Unbenched),
+            "all" => Ok(Term::All),
+            s => {
+                if let Some(n) = s.strip_prefix("slowest:") {
+                    return n
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .map(Term::Slowest)
+                        .ok_or(format!("`{s}`: expected a positive number of days."));
+                }
+                match s.split_once('-') {
+                    Some((from, to)) => {
+                        let (from, to) = (day(from)?, day(to)?);
+                        if from > to {
+                            return Err(format!("`{s}`: the range is empty."));
+                        }
+                        Ok(Term::Range(from, to))
+                    }
+                    None => day(s).map(|day| Term::Range(day, day)),
+                }
+            }
+        }
+    }
+}
+
+/// A parsed day-set expression. Use [`DaySet::resolve`] to get the selected days.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaySet {
+    include: Vec<Term>,
+    exclude: Vec<Term>,
+}
+
+impl DaySet {
+    /// A set containing a single day.
+    pub fn single(day: Day) -> Self {
+        Self {
+            include: vec![Term::Range(day, day)],
+            exclude: vec![],
+        }
+    }
+
+    /// Returns the single day this set consists of, e.g. `7` or `7-7`.
+    pub fn as_single(&self) -> Option<Day> {
+        match (self.include.as_slice(), self.exclude.as_slice()) {
+            ([Term::Range(from, to)], []) if from == to => Some(*from),
+            _ => None,
+        }
+    }
+
+    /// Selects the days of the expression, reading `answers.json` and `timings.json`.
+    pub fn resolve(&self) -> HashSet<Day> {
+        self.resolve_with(&Context {
+            scaffolded: all_days()
+                .filter(|day| get_path_for_bin(*day).exists())
+                .collect(),
+            answers: Answers::read_from_file(),
+            timings: Timings::read_from_file(),
+        })
+    }
+
+    fn resolve_with(&self, context: &Context) -> HashSet<Day> {
+        let mut days: HashSet<Day> = if self.include.is_empty() {
+            all_days().collect()
+        } else {
+            self.include.iter().flat_map(|t| context.days(*t)).collect()
+        };
+        for term in &self.exclude {
+            for day in context.days(*term) {
+                days.remove(&day);
+            }
+        }
+        days
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self {
+            include: vec![],
+            exclude: vec![],
+        };
+
+        for term in s.split(',').map(str::trim) {
+            let result = match term.strip_prefix('!') {
+                Some(term) => term.trim().parse().map(|t| set.exclude.push(t)),
+                None => term.parse().map(|t| set.include.push(t)),
+            };
+            result.map_err(|message| DaySetError {
+                expression: s.to_string(),
+                message,
+            })?;
+        }
+
+        Ok(set)
+    }
+}
+
+/// The project state that the terms `unsolved`, `unbenched` and `slowest:N` depend on.
+struct Context {
+    scaffolded: HashSet<Day>,
+    answers: Answers,
+    timings: Timings,
+}
+
+impl Context {
+    fn days(&self, term: Term) -> Vec<Day> {
+        match term {
+            Term::Range(from, to) => all_days().filter(|d| *d >= from && *d <= to).collect(),
+            Term::All => all_days().collect(),
+            Term::Unsolved => all_days()
+                .filter(|day| self.scaffolded.contains(day))
+                .filter(|day| {
+                    self.answers.get(*day, 1).is_none() || self.answers.get(*day, 2).is_none()
+                })
+                .collect(),
+            Term::Unbenched => all_days()
+                .filter(|day| !self.timings.is_day_complete(*day))
+                .collect(),
+            Term::Slowest(n) => {
+                let mut timings = self.timings.data.clone();
+                timings.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+                timings.iter().take(n).map(|t| t.day).collect()
+            }
+        }
+    }
+}
+
+/// An invalid day-set expression, naming the offending term.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DaySetError {
+    pub expression: String,
+    pub message: String,
+}
+
+impl std::error::Error for DaySetError {}
+
+impl Display for DaySetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid day set `{}`: {} Use e.g. `1-10`, `3,7,12`, `!5`, `unsolved`, `unbenched` or `slowest:5`.",
+            self.expression, self.message
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{Context, DaySet, Term};
+    use crate::day;
+    use crate::template::answers::{Answer, Answers};
+    use crate::template::timings::{Timing, Timings};
+    use crate::template::Day;
+
+    fn context() -> Context {
+        let timing = |day: Day, total_nanos: f64| Timing {
+            day,
+            part_1: Some("1ms".into()),
+            part_2: (total_nanos > 0.0).then(|| "1ms".into()),
+            total_nanos,
+        };
+        Context {
+            scaffolded: [day!(1), day!(2), day!(3), day!(4)].into(),
+            answers: Answers {
+                data: vec![
+                    Answer {
+                        day: day!(1),
+                        part_1: Some("1".into()),
+                        part_2: Some("2".into()),
+                    },
+                    Answer {
+                        day: day!(2),
+                        part_1: Some("1".into()),
+                        part_2: None,
+                    },
+                ],
+            },
+            timings: Timings {
+                data: vec![
+                    timing(day!(1), 5.0),
+                    timing(day!(2), 50.0),
+                    timing(day!(3), 20.0),
+                    timing(day!(4), 0.0),
+                ],
+            },
+        }
+    }
+
+    fn resolve(s: &str) -> Vec<u8> {
+        let mut days: Vec<u8> = s
+            .parse::<DaySet>()
+            .unwrap()
+            .resolve_with(&context())
+            .into_iter()
+            .map(Day::into_inner)
+            .collect();
+        days.sort_unstable();
+        days
+    }
+
+    #[test]
+    fn parses_terms() {
+        assert_eq!("7".parse(), Ok(Term::Range(day!(7), day!(7))));
+        assert_eq!("01-10".parse(), Ok(Term::Range(day!(1), day!(10))));
+        assert_eq!("slowest:3".parse(), Ok(Term::Slowest(3)));
+        assert!("10-1".parse::<Term>().is_err());
+        assert!("26".parse::<Term>().is_err());
+        assert!("slowest:0".parse::<Term>().is_err());
+        assert!("".parse::<Term>().is_err());
+    }
+
+    #[test]
+    fn selects_days() {
+        assert_eq!(resolve("3,7,12"), vec![3, 7, 12]);
+        assert_eq!(resolve("1-10,!5,!7-9"), vec![1, 2, 3, 4, 6, 10]);
+        assert_eq!(resolve("!2-25"), vec![1]);
+        assert_eq!(resolve("unsolved"), vec![2, 3, 4]);
+        assert_eq!(resolve("unbenched"), (4..=25).collect::<Vec<_>>());
+        assert_eq!(resolve("slowest:2"), vec![2, 3]);
+        assert_eq!(resolve("unsolved,!slowest:1"), vec![3, 4]);
+        assert_eq!(resolve("all").len(), 25);
+    }
+
+    #[test]
+    fn reports_offending_terms() {
+        let err = "1-3,x".parse::<DaySet>().unwrap_err();
+        assert_eq!(err.expression, "1-3,x");
+        assert!(err.message.starts_with("`x`"));
+        assert!(err
+            .to_string()
+            .starts_with("invalid day set `1-3,x`: `x`: expecting"));
+    }
+
+    #[test]
+    fn detects_single_days() {
+        assert_eq!("7".parse::<DaySet>().unwrap().as_single(), Some(day!(7)));
+        assert_eq!("7-8".parse::<DaySet>().unwrap().as_single(), None);
+        assert_eq!(
+            DaySet::single(day!(3)).resolve_with(&context()),
+            HashSet::from([day!(3)])
+        );
+    }
+}
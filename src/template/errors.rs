@@ -0,0 +1,139 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io;
+use std::path::PathBuf;
+
+/// An error which can be returned when reading a data file, naming the file that could not be read.
+#[derive(Debug)]
+pub struct ReadFileError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "could not read \"{}\": {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned by a solution that failed to parse its input.
+///
+/// Line and column numbers start at 1. The runner prints them together with the input path.
+///
+/// ```
+/// # use advent_of_code::template::ParseError;
+/// let input = "1 2\n3 x";
+/// let token = input.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+/// let err = ParseError::in_input(input, token, "expected a number");
+/// assert_eq!(err.to_string(), "line 2, column 3: expected a number");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates a [`ParseError`] without a position.
+    pub fn new(message: impl Display) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates a [`ParseError`] pointing at a whole line.
+    pub fn on_line(line: usize, message: impl Display) -> Self {
+        Self {
+            line: Some(line),
+            ..Self::new(message)
+        }
+    }
+
+    /// Creates a [`ParseError`] pointing at a line and column.
+    pub fn at(line: usize, column: usize, message: impl Display) -> Self {
+        Self {
+            line: Some(line),
+            column: Some(column),
+            ..Self::new(message)
+        }
+    }
+
+    /// Creates a [`ParseError`] pointing at `fragment`, which must be a sub-slice of `input`
+    /// (as returned by e.g. `lines()` or `split()`). Falls back to no position otherwise.
+    pub fn in_input(input: &str, fragment: &str, message: impl Display) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(start);
+
+        if offset.saturating_add(fragment.len()) > input.len() || !input.is_char_boundary(offset) {
+            return Self::new(message);
+        }
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = input[line_start..offset].chars().count() + 1;
+
+        Self::at(line, column, message)
+    }
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            _ => {}
+        }
+        f.write_str(&self.message)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::ParseError;
+
+    #[test]
+    fn locates_fragments() {
+        let input = "abc\ndef\nghi";
+        let fragment = &input[9..];
+        let err = ParseError::in_input(input, fragment, "bad");
+        assert_eq!((err.line, err.column), (Some(3), Some(2)));
+    }
+
+    #[test]
+    fn ignores_foreign_fragments() {
+        let input = String::from("abc");
+        let err = ParseError::in_input(&input, "other", "bad");
+        assert_eq!(err, ParseError::new("bad"));
+    }
+
+    #[test]
+    fn formats_positions() {
+        assert_eq!(ParseError::new("bad").to_string(), "bad");
+        assert_eq!(ParseError::on_line(4, "bad").to_string(), "line 4: bad");
+        assert_eq!(
+            ParseError::at(4, 2, "bad").to_string(),
+            "line 4, column 2: bad"
+        );
+    }
+}
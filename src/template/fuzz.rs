@@ -0,0 +1,363 @@
+/// Property testing of solutions against brute-force reference implementations.
+///
+/// A day registers a [`Fuzzer`] with the `solution!` macro. Running the day binary with `--fuzz` then
+/// generates random puzzle inputs, compares each candidate to its oracle and shrinks failing inputs
+/// before saving them to `data/regressions/<day>/`.
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs, process};
+
+use crate::template::{Config, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// A small, seedable pseudo-random number generator (`SplitMix64`).
+///
+/// Not suitable for anything but generating test inputs.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in the inclusive range `lo..=hi`.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {lo}..={hi}");
+        let span = hi.abs_diff(lo).wrapping_add(1);
+        if span == 0 {
+            // the range covers all of `i64`.
+            #[allow(clippy::cast_possible_wrap)]
+            return self.next_u64() as i64;
+        }
+        #[allow(clippy::cast_possible_wrap)]
+        lo.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    /// Returns `true` with a probability of `1 / n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64().is_multiple_of(n.max(1))
+    }
+
+    /// Picks a random element of a non-empty slice.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = (self.next_u64() % items.len() as u64) as usize;
+        &items[index]
+    }
+}
+
+/// A pair of implementations that are expected to agree on every valid input.
+pub struct Oracle<T: 'static> {
+    pub name: &'static str,
+    /// The optimized implementation under test.
+    pub candidate: fn(&str) -> Option<T>,
+    /// A slow but obviously correct reference implementation.
+    pub reference: fn(&str) -> Option<T>,
+}
+
+/// Describes how to fuzz a day: an input generator and the oracle pairs to check.
+pub struct Fuzzer<T: 'static> {
+    /// Generates a random, valid puzzle input. `size` grows with the case number, starting at 1.
+    pub generate: fn(&mut Rng, usize) -> String,
+    pub oracles: &'static [Oracle<T>],
+}
+
+/// Options for a fuzzing session, parsed from the command-line of a solution binary.
+#[derive(Clone, Copy, Debug)]
+pub struct FuzzOptions {
+    pub cases: u32,
+    pub seed: u64,
+    pub max_size: usize,
+}
+
+impl FuzzOptions {
+    fn from_env() -> Result<Self, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_env();
+        let seed = match args.opt_value_from_str("--seed")? {
+            Some(seed) => seed,
+            None => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        };
+
+        Ok(Self {
+            cases: args.opt_value_from_str("--cases")?.unwrap_or(100),
+            seed,
+            max_size: args.opt_value_from_str("--size")?.unwrap_or(10),
+        })
+    }
+}
+
+/// Outcome of running one oracle pair on one input.
+#[derive(Debug, PartialEq, Eq)]
+enum Verdict {
+    Agree,
+    Disagree {
+        candidate: String,
+        reference: String,
+    },
+    /// The reference panicked, so the input is treated as invalid and discarded.
+    Invalid,
+}
+
+fn call_silently<T>(func: fn(&str) -> Option<T>, input: &str) -> Result<Option<T>, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| func(input))).map_err(|e| {
+        e.downcast_ref::<&str>()
+            .map(|s| (*s).to_string())
+            .or_else(|| e.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".into())
+    })
+}
+
+fn format_answer<T: Display>(answer: &Option<T>) -> String {
+    match answer {
+        Some(answer) => answer.to_string(),
+        None => "None".into(),
+    }
+}
+
+fn check<T: Display + PartialEq>(oracle: &Oracle<T>, input: &str) -> Verdict {
+    let Ok(reference) = call_silently(oracle.reference, input) else {
+        return Verdict::Invalid;
+    };
+
+    match call_silently(oracle.candidate, input) {
+        Ok(candidate) if candidate == reference => Verdict::Agree,
+        Ok(candidate) => Verdict::Disagree {
+            candidate: format_answer(&candidate),
+            reference: format_answer(&reference),
+        },
+        Err(msg) => Verdict::Disagree {
+            candidate: format!("panic: {msg}"),
+            reference: format_answer(&reference),
+        },
+    }
+}
+
+/// Reduces a failing input by removing blank-line separated sections first, then single lines,
+/// for as long as `fails` keeps returning `true`.
+fn shrink_input(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut current = input.to_string();
+
+    for separator in ["\n\n", "\n"] {
+        loop {
+            let pieces: Vec<&str> = current.split(separator).collect();
+            if pieces.len() < 2 {
+                break;
+            }
+
+            let smaller = (0..pieces.len()).find_map(|skip| {
+                let candidate = pieces
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != skip)
+                    .map(|(_, p)| *p)
+                    .collect::<Vec<_>>()
+                    .join(separator);
+                (!candidate.trim().is_empty() && fails(&candidate)).then_some(candidate)
+            });
+
+            match smaller {
+                Some(smaller) => current = smaller,
+                None => break,
+            }
+        }
+    }
+
+    current
+}
+
+fn case_seed(seed: u64, case: u32) -> u64 {
+    Rng::new(seed ^ u64::from(case).wrapping_mul(0x2545_F491_4F6C_DD1D)).next_u64()
+}
+
+fn case_size(case: u32, max_size: usize) -> usize {
+    case as usize % max_size.max(1) + 1
+}
+
+fn get_regressions_dir(day: Day) -> PathBuf {
+    Config::get()
+        .paths
+        .data
+        .join("regressions")
+        .join(day.to_string())
+}
+
+fn save_regression(day: Day, name: &str, seed: u64, case: u32, input: &str) -> Option<PathBuf> {
+    let dir = get_regressions_dir(day);
+    fs::create_dir_all(&dir).ok()?;
+    let path = dir.join(format!("{name}-{seed}-{case}.txt"));
+    fs::write(&path, input).ok()?;
+    Some(path)
+}
+
+/// Runs a fuzzing session for `day`. Exits with a non-zero status if any oracle pair disagreed.
+pub fn run<T: Display + PartialEq>(fuzzer: &Fuzzer<T>, day: Day) {
+    let options = match FuzzOptions::from_env() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Unexpected command-line input: {e}. Format: cargo fuzz-day 1 --cases 1000 --seed 42");
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "{ANSI_BOLD}Fuzzing day {day}{ANSI_RESET} {ANSI_ITALIC}({} cases, seed {}){ANSI_RESET}",
+        options.cases, options.seed
+    );
+
+    // silence panic messages from candidates, they are reported as failures instead.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let failures = fuzz_cases(fuzzer, day, options);
+    panic::set_hook(default_hook);
+
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+fn fuzz_cases<T: Display + PartialEq>(fuzzer: &Fuzzer<T>, day: Day, options: FuzzOptions) -> u32 {
+    let mut failures = 0;
+
+    for oracle in fuzzer.oracles {
+        let mut discarded = 0;
+        let mut failed = None;
+
+        for case in 0..options.cases {
+            let seed = case_seed(options.seed, case);
+            let size = case_size(case, options.max_size);
+            let input = (fuzzer.generate)(&mut Rng::new(seed), size);
+
+            match check(oracle, &input) {
+                Verdict::Agree => {}
+                Verdict::Invalid => discarded += 1,
+                Verdict::Disagree { .. } => {
+                    failed = Some((case, seed, size, input));
+                    break;
+                }
+            }
+        }
+
+        let Some((case, seed, size, input)) = failed else {
+            println!(
+                "{}: {ANSI_BOLD}ok{ANSI_RESET} ({discarded} invalid inputs discarded)",
+                oracle.name
+            );
+            continue;
+        };
+
+        failures += 1;
+        let fails = |s: &str| matches!(check(oracle, s), Verdict::Disagree { .. });
+
+        // prefer the smallest generator size that still reproduces the failure, then shrink the text.
+        let smallest = (1..size)
+            .map(|size| (fuzzer.generate)(&mut Rng::new(seed), size))
+            .find(|s| fails(s))
+            .unwrap_or(input);
+        let shrunk = shrink_input(&smallest, fails);
+
+        println!(
+            "{}: {ANSI_BOLD}failed{ANSI_RESET} on case {case}",
+            oracle.name
+        );
+        if let Verdict::Disagree {
+            candidate,
+            reference,
+        } = check(oracle, &shrunk)
+        {
+            println!("  candidate: {candidate}");
+            println!("  reference: {reference}");
+        }
+        println!("  input ({} lines):", shrunk.lines().count());
+        shrunk.lines().for_each(|l| println!("    {l}"));
+
+        match save_regression(day, oracle.name, options.seed, case, &shrunk) {
+            Some(path) => println!("  saved to \"{}\"", path.display()),
+            None => eprintln!("  could not save regression input."),
+        }
+    }
+
+    failures
+}
+
+/// Returns `true` if the solution binary was invoked in fuzzing mode.
+pub fn is_requested() -> bool {
+    env::args().any(|x| x == "--fuzz")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{case_seed, check, shrink_input, Oracle, Rng, Verdict};
+
+    #[test]
+    fn rng_is_deterministic() {
+        let a: Vec<u64> = (0..5).map(|_| Rng::new(7).next_u64()).collect();
+        assert!(a.windows(2).all(|w| w[0] == w[1]));
+        assert_ne!(case_seed(7, 0), case_seed(7, 1));
+    }
+
+    #[test]
+    fn rng_range_is_inclusive() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            let x = rng.range(-2, 2);
+            assert!((-2..=2).contains(&x));
+        }
+        assert_eq!(rng.range(5, 5), 5);
+    }
+
+    fn sum(input: &str) -> Option<i64> {
+        Some(input.lines().map(|l| l.parse::<i64>().unwrap()).sum())
+    }
+
+    fn sum_without_sevens(input: &str) -> Option<i64> {
+        Some(
+            input
+                .lines()
+                .map(|l| l.parse::<i64>().unwrap())
+                .filter(|x| *x != 7)
+                .sum(),
+        )
+    }
+
+    static ORACLE: Oracle<i64> = Oracle {
+        name: "sum",
+        candidate: sum_without_sevens,
+        reference: sum,
+    };
+
+    #[test]
+    fn detects_disagreement() {
+        assert_eq!(check(&ORACLE, "1\n2"), Verdict::Agree);
+        assert_eq!(
+            check(&ORACLE, "1\n7"),
+            Verdict::Disagree {
+                candidate: "1".into(),
+                reference: "8".into()
+            }
+        );
+        assert_eq!(check(&ORACLE, "x"), Verdict::Invalid);
+    }
+
+    #[test]
+    fn shrinks_to_minimal_input() {
+        let fails = |s: &str| matches!(check(&ORACLE, s), Verdict::Disagree { .. });
+        assert_eq!(shrink_input("1\n2\n7\n4\n\n5", fails), "7");
+    }
+}
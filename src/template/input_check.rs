@@ -0,0 +1,197 @@
+/// Sanity checks that run on a puzzle input before the runner calls a part.
+///
+/// A failed download or a copy-paste mistake usually surfaces as an index-out-of-bounds panic deep
+/// inside a solution. These checks catch the common cases up front and name the file to fix.
+use std::fmt::Display;
+use std::path::Path;
+use std::process;
+
+use crate::template::{Config, Day};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The part is still run.
+    Warning,
+    /// The part is not run.
+    Error,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputIssue {
+    Empty,
+    /// An HTML page or an error response of the website, e.g. from a failed download.
+    ErrorPage,
+    /// The puzzle description instead of the puzzle input.
+    PuzzleText,
+    CrlfLineEndings,
+    MissingTrailingNewline,
+}
+
+impl InputIssue {
+    pub fn severity(self) -> Severity {
+        match self {
+            InputIssue::Empty | InputIssue::ErrorPage | InputIssue::PuzzleText => Severity::Error,
+            InputIssue::CrlfLineEndings | InputIssue::MissingTrailingNewline => Severity::Warning,
+        }
+    }
+}
+
+impl Display for InputIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputIssue::Empty => write!(f, "the file is empty."),
+            InputIssue::ErrorPage => write!(
+                f,
+                "the file contains a web page or error message instead of a puzzle input. The download probably failed, check your session cookie."
+            ),
+            InputIssue::PuzzleText => write!(
+                f,
+                "the file contains the puzzle description instead of the puzzle input."
+            ),
+            InputIssue::CrlfLineEndings => write!(
+                f,
+                "the file uses CRLF line endings. Solutions splitting on '\\n' will see a trailing '\\r' on every line."
+            ),
+            InputIssue::MissingTrailingNewline => write!(
+                f,
+                "the file does not end with a newline, it may have been truncated when copying."
+            ),
+        }
+    }
+}
+
+/// Messages of the website that are saved as input when a download fails.
+const ERROR_PAGE_MARKERS: &[&str] = &[
+    "Puzzle inputs differ by user.",
+    "Please don't repeatedly request this endpoint before it unlocks!",
+    "404 Not Found",
+];
+
+/// Phrases that only appear in puzzle descriptions.
+const PUZZLE_TEXT_MARKERS: &[&str] = &[
+    "--- Part Two ---",
+    "Your puzzle answer was",
+    "To begin, get your puzzle input",
+];
+
+/// Returns all issues found in `input`, errors first.
+pub fn check_input(input: &str) -> Vec<InputIssue> {
+    let trimmed = input.trim_start();
+
+    if trimmed.is_empty() {
+        return vec![InputIssue::Empty];
+    }
+
+    let head = trimmed
+        .get(..trimmed.len().min(100))
+        .unwrap_or(trimmed)
+        .to_ascii_lowercase();
+    if head.starts_with("<!doctype html")
+        || head.starts_with("<html")
+        || ERROR_PAGE_MARKERS.iter().any(|m| trimmed.starts_with(m))
+    {
+        return vec![InputIssue::ErrorPage];
+    }
+
+    let first_line = trimmed.lines().next().unwrap_or_default();
+    let is_title = first_line
+        .trim_start_matches(['#', '\\', ' '])
+        .starts_with("--- Day ");
+    if is_title || PUZZLE_TEXT_MARKERS.iter().any(|m| input.contains(m)) {
+        return vec![InputIssue::PuzzleText];
+    }
+
+    let mut issues = vec![];
+    if input.contains("\r\n") {
+        issues.push(InputIssue::CrlfLineEndings);
+    }
+    if !input.ends_with('\n') {
+        issues.push(InputIssue::MissingTrailingNewline);
+    }
+    issues
+}
+
+/// Checks the input of `day`, printing warnings and exiting with an error message if the input
+/// cannot be a valid puzzle input.
+pub fn check_or_exit(day: Day, path: &Path, input: &str) {
+    let issues = check_input(input);
+
+    for issue in &issues {
+        match issue.severity() {
+            Severity::Warning => eprintln!("Warning: {}: {issue}", path.display()),
+            Severity::Error => {
+                eprintln!("Error: {}: {issue} {}", path.display(), hint(day, *issue));
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn hint(day: Day, issue: InputIssue) -> String {
+    let url = match Config::get().year() {
+        Some(year) => format!(
+            "https://adventofcode.com/{year}/day/{}/input",
+            day.into_inner()
+        ),
+        None => "the puzzle page".into(),
+    };
+
+    match issue {
+        InputIssue::PuzzleText => format!("Copy your input from {url}."),
+        _ => format!("Run `cargo download {day}` or copy your input from {url}."),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_input, InputIssue};
+
+    #[test]
+    fn accepts_valid_inputs() {
+        assert_eq!(check_input("3   4\n4   3\n"), []);
+        assert_eq!(check_input("<<>>\n"), []);
+    }
+
+    #[test]
+    fn detects_empty_inputs() {
+        assert_eq!(check_input(""), [InputIssue::Empty]);
+        assert_eq!(check_input("\n \n"), [InputIssue::Empty]);
+    }
+
+    #[test]
+    fn detects_error_pages() {
+        assert_eq!(
+            check_input("<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            [InputIssue::ErrorPage]
+        );
+        assert_eq!(
+            check_input("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            [InputIssue::ErrorPage]
+        );
+    }
+
+    #[test]
+    fn detects_puzzle_text() {
+        assert_eq!(
+            check_input("\\--- Day 4: Ceres Search ---\n----------\n"),
+            [InputIssue::PuzzleText]
+        );
+        assert_eq!(
+            check_input("MMMSXXMASM\nYour puzzle answer was `18`.\n"),
+            [InputIssue::PuzzleText]
+        );
+    }
+
+    #[test]
+    fn detects_formatting_issues() {
+        assert_eq!(
+            check_input("1\r\n2"),
+            [
+                InputIssue::CrlfLineEndings,
+                InputIssue::MissingTrailingNewline
+            ]
+        );
+    }
+}
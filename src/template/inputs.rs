@@ -0,0 +1,426 @@
+/// Encrypted bundle of puzzle inputs, so that inputs can be committed without publishing them.
+///
+/// The bundle at `paths.inputs_bundle` is a text file with a header naming the fingerprint of the
+/// key, followed by one `<day> <nonce> <ciphertext>` line per input (hex encoded). Inputs are
+/// encrypted with ChaCha20-Poly1305 under a key derived from a passphrase, which is read from the
+/// `AOC_INPUTS_KEY` environment variable or from the key file at `paths.inputs_key`.
+///
+/// Nonces are derived from the key, day and input, so re-encrypting an unchanged input yields the
+/// same line and does not show up as a change in git.
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write as _};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use sha2::{Digest, Sha256};
+
+use crate::template::{Config, Day};
+
+/// The environment variable holding the key. Takes precedence over the key file.
+pub const KEY_ENV: &str = "AOC_INPUTS_KEY";
+
+const HEADER: &str = "aoc-inputs v1";
+
+/// A key derived from a passphrase.
+pub struct Key([u8; 32]);
+
+impl Key {
+    /// Derives a key from a passphrase. Surrounding whitespace is ignored.
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        Self(Sha256::digest(passphrase.trim().as_bytes()).into())
+    }
+
+    /// Reads the key from [`KEY_ENV`] or, if that is not set, from `paths.inputs_key`.
+    pub fn load() -> Result<Self, BundleError> {
+        let key_file = &Config::get().paths.inputs_key;
+
+        let passphrase = match env::var(KEY_ENV) {
+            Ok(s) => s,
+            Err(_) => match fs::read_to_string(key_file) {
+                Ok(s) => s,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+                Err(source) => {
+                    return Err(BundleError::Io {
+                        path: key_file.clone(),
+                        source,
+                    })
+                }
+            },
+        };
+
+        if passphrase.trim().is_empty() {
+            return Err(BundleError::MissingKey {
+                key_file: key_file.clone(),
+            });
+        }
+
+        Ok(Self::from_passphrase(&passphrase))
+    }
+
+    /// A short, non-secret identifier of the key, stored in the bundle header.
+    pub fn fingerprint(&self) -> String {
+        let digest = Sha256::new()
+            .chain_update(b"fingerprint")
+            .chain_update(self.0)
+            .finalize();
+        to_hex(&digest[..4])
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&self.0.into())
+    }
+
+    fn nonce(&self, day: Day, plaintext: &[u8]) -> [u8; 12] {
+        let digest = Sha256::new()
+            .chain_update(b"nonce")
+            .chain_update(self.0)
+            .chain_update([day.into_inner()])
+            .chain_update(plaintext)
+            .finalize();
+        digest[..12].try_into().unwrap()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry {
+    nonce: [u8; 12],
+    ciphertext: Vec<u8>,
+}
+
+/// The encrypted inputs of a project, all under the same key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bundle {
+    /// The [`Key::fingerprint`] of the key the inputs were encrypted with.
+    pub fingerprint: String,
+    entries: BTreeMap<Day, Entry>,
+}
+
+impl Bundle {
+    /// Creates an empty bundle for `key`.
+    pub fn new(key: &Key) -> Self {
+        Self {
+            fingerprint: key.fingerprint(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Reads the bundle from `path`. Returns `Ok(None)` if the file does not exist.
+    pub fn load(path: &Path) -> Result<Option<Self>, BundleError> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(BundleError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    /// Parses the contents of a bundle file. Blank lines are skipped.
+    pub fn parse(s: &str) -> Result<Self, BundleError> {
+        let malformed = |line: usize, message: &str| BundleError::Malformed {
+            line,
+            message: message.into(),
+        };
+
+        let mut lines = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        let fingerprint = match lines.next() {
+            Some((_, line)) => line
+                .strip_prefix(HEADER)
+                .and_then(|rest| rest.trim().strip_prefix("key:"))
+                .filter(|fingerprint| !fingerprint.is_empty())
+                .ok_or_else(|| malformed(1, &format!("expected `{HEADER} key:<fingerprint>`.")))?
+                .to_string(),
+            None => return Err(malformed(1, "the bundle is empty.")),
+        };
+
+        let mut entries = BTreeMap::new();
+
+        for (n, line) in lines {
+            let mut fields = line.split_whitespace();
+            let day: Day = fields
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| malformed(n, "expected a day."))?;
+            let nonce = fields
+                .next()
+                .and_then(from_hex)
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| malformed(n, "expected a 12 byte nonce."))?;
+            let ciphertext = fields
+                .next()
+                .and_then(from_hex)
+                .ok_or_else(|| malformed(n, "expected the encrypted input."))?;
+
+            if fields.next().is_some() {
+                return Err(malformed(n, "unexpected trailing data."));
+            }
+            if entries.insert(day, Entry { nonce, ciphertext }).is_some() {
+                return Err(malformed(n, &format!("day {day} is listed twice.")));
+            }
+        }
+
+        Ok(Self {
+            fingerprint,
+            entries,
+        })
+    }
+
+    /// The days with an encrypted input.
+    pub fn days(&self) -> impl Iterator<Item = Day> + '_ {
+        self.entries.keys().copied()
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.entries.contains_key(&day)
+    }
+
+    /// Encrypts `input` and stores it for `day`, replacing a previous input.
+    pub fn insert(&mut self, key: &Key, day: Day, input: &str) -> Result<(), BundleError> {
+        self.check_key(key)?;
+
+        let nonce = key.nonce(day, input.as_bytes());
+        let ciphertext = key
+            .cipher()
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: input.as_bytes(),
+                    aad: &[day.into_inner()],
+                },
+            )
+            .map_err(|_| BundleError::Corrupt(day))?;
+
+        self.entries.insert(day, Entry { nonce, ciphertext });
+        Ok(())
+    }
+
+    /// Decrypts the input of `day`. Returns `Ok(None)` if the bundle has no input for `day`.
+    pub fn decrypt(&self, key: &Key, day: Day) -> Result<Option<String>, BundleError> {
+        let Some(entry) = self.entries.get(&day) else {
+            return Ok(None);
+        };
+        self.check_key(key)?;
+
+        let plaintext = key
+            .cipher()
+            .decrypt(
+                Nonce::from_slice(&entry.nonce),
+                Payload {
+                    msg: &entry.ciphertext,
+                    aad: &[day.into_inner()],
+                },
+            )
+            .map_err(|_| BundleError::Corrupt(day))?;
+
+        String::from_utf8(plaintext)
+            .map(Some)
+            .map_err(|_| BundleError::Corrupt(day))
+    }
+
+    fn check_key(&self, key: &Key) -> Result<(), BundleError> {
+        let found = key.fingerprint();
+        if found == self.fingerprint {
+            Ok(())
+        } else {
+            Err(BundleError::WrongKey {
+                expected: self.fingerprint.clone(),
+                found,
+            })
+        }
+    }
+}
+
+impl Display for Bundle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{HEADER} key:{}", self.fingerprint)?;
+        for (day, entry) in &self.entries {
+            writeln!(
+                f,
+                "{day} {} {}",
+                to_hex(&entry.nonce),
+                to_hex(&entry.ciphertext)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Decrypts the input of `day` from the bundle at `paths.inputs_bundle`.
+/// Returns `Ok(None)` if there is no bundle or it has no input for `day`. The key is only loaded
+/// if it is needed.
+pub fn decrypt_input(day: Day) -> Result<Option<String>, BundleError> {
+    let path = &Config::get().paths.inputs_bundle;
+    match Bundle::load(path)? {
+        Some(bundle) if bundle.contains(day) => bundle.decrypt(&Key::load()?, day),
+        _ => Ok(None),
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{b:02x}");
+        s
+    })
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when reading or writing the inputs bundle.
+#[derive(Debug)]
+pub enum BundleError {
+    /// Neither [`KEY_ENV`] nor the key file is set.
+    MissingKey {
+        key_file: PathBuf,
+    },
+    /// The key does not match the fingerprint in the bundle.
+    WrongKey {
+        expected: String,
+        found: String,
+    },
+    /// An input could not be authenticated, i.e. the bundle was modified.
+    Corrupt(Day),
+    Malformed {
+        line: usize,
+        message: String,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl std::error::Error for BundleError {}
+
+impl Display for BundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BundleError::MissingKey { key_file } => write!(
+                f,
+                "the inputs bundle needs a key. Set {KEY_ENV} or write the key to \"{}\".",
+                key_file.display()
+            ),
+            BundleError::WrongKey { expected, found } => write!(
+                f,
+                "the inputs bundle was encrypted with key {expected}, but the configured key is {found}. Check {KEY_ENV} or the key file."
+            ),
+            BundleError::Corrupt(day) => write!(
+                f,
+                "the input of day {day} in the inputs bundle could not be decrypted, the bundle was modified."
+            ),
+            BundleError::Malformed { line, message } => {
+                write!(f, "the inputs bundle is malformed: line {line}: {message}")
+            }
+            BundleError::Io { path, source } => {
+                write!(f, "could not access \"{}\": {source}", path.display())
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Bundle, BundleError, Key};
+    use crate::template::Day;
+
+    fn day(n: u8) -> Day {
+        Day::new(n).unwrap()
+    }
+
+    #[test]
+    fn round_trips() {
+        let key = Key::from_passphrase("correct horse battery staple\n");
+        let mut bundle = Bundle::new(&key);
+        bundle.insert(&key, day(1), "3   4\n4   3\n").unwrap();
+        bundle.insert(&key, day(12), "AAAA\nBBCD\n").unwrap();
+
+        let parsed = Bundle::parse(&bundle.to_string()).unwrap();
+        assert_eq!(parsed, bundle);
+        assert_eq!(parsed.days().collect::<Vec<_>>(), vec![day(1), day(12)]);
+        assert_eq!(
+            parsed.decrypt(&key, day(12)).unwrap().as_deref(),
+            Some("AAAA\nBBCD\n")
+        );
+        assert_eq!(parsed.decrypt(&key, day(2)).unwrap(), None);
+    }
+
+    #[test]
+    fn encrypts_deterministically() {
+        let key = Key::from_passphrase("secret");
+        let mut a = Bundle::new(&key);
+        let mut b = Bundle::new(&key);
+        a.insert(&key, day(1), "1 2 3").unwrap();
+        b.insert(&key, day(1), "1 2 3").unwrap();
+        assert_eq!(a.to_string(), b.to_string());
+        assert!(!a.to_string().contains("1 2 3"));
+
+        b.insert(&key, day(2), "1 2 3").unwrap();
+        let lines: Vec<String> = b.to_string().lines().map(|l| l[3..].into()).collect();
+        assert_ne!(lines[1], lines[2]);
+    }
+
+    #[test]
+    fn rejects_wrong_keys() {
+        let key = Key::from_passphrase("secret");
+        let mut bundle = Bundle::new(&key);
+        bundle.insert(&key, day(1), "input").unwrap();
+
+        let other = Key::from_passphrase("guess");
+        let err = bundle.decrypt(&other, day(1)).unwrap_err();
+        assert!(matches!(err, BundleError::WrongKey { .. }));
+        assert!(err.to_string().contains(&key.fingerprint()));
+        assert!(bundle.insert(&other, day(2), "input").is_err());
+    }
+
+    #[test]
+    fn detects_tampering() {
+        let key = Key::from_passphrase("secret");
+        let mut bundle = Bundle::new(&key);
+        bundle.insert(&key, day(1), "input").unwrap();
+
+        // Moving an input to another day is detected, as the day is authenticated.
+        let moved = bundle.to_string().replace("\n01 ", "\n02 ");
+        assert!(matches!(
+            Bundle::parse(&moved).unwrap().decrypt(&key, day(2)),
+            Err(BundleError::Corrupt(_))
+        ));
+    }
+
+    #[test]
+    fn reports_malformed_bundles() {
+        let line = |s: &str| match Bundle::parse(s) {
+            Err(BundleError::Malformed { line, .. }) => line,
+            other => panic!("expected a malformed bundle, got {other:?}"),
+        };
+        assert_eq!(line(""), 1);
+        assert_eq!(line("inputs"), 1);
+        assert_eq!(line("aoc-inputs v1 key:abcd\n01 00ff"), 2);
+        assert_eq!(
+            line("aoc-inputs v1 key:abcd\n\n01 000000000000000000000000 zz"),
+            3
+        );
+        assert_eq!(
+            line("aoc-inputs v1 key:abcd\n01 000000000000000000000000 00\n01 000000000000000000000000 00"),
+            3
+        );
+    }
+}
@@ -0,0 +1,376 @@
+/// Parses and formats the JSON export of a private leaderboard
+/// (`https://adventofcode.com/<year>/leaderboard/private/view/<id>.json`).
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// A member of a private leaderboard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// Members can choose to stay anonymous.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u32,
+    /// Unix timestamps at which the first and second star of a day were collected.
+    pub completions: BTreeMap<Day, [Option<i64>; 2]>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    fn star_ts(&self, day: Day, part: usize) -> Option<i64> {
+        self.completions.get(&day).and_then(|c| c[part])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: u16,
+    pub members: Vec<Member>,
+}
+
+impl FromStr for Leaderboard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = JsonValue::from_str(s).or(Err("not valid JSON file."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let event = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| v.parse().ok())
+            .ok_or("expected `event` to be a year.")?;
+
+        let members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `members` to be an object.")?
+            .values()
+            .map(parse_member)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Leaderboard { event, members })
+    }
+}
+
+fn as_u64(value: &JsonValue) -> Option<u64> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    value.get::<f64>().filter(|x| **x >= 0.0).map(|x| *x as u64)
+}
+
+fn parse_member(value: &JsonValue) -> Result<Member, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected member to be an object.")?;
+
+    let number = |key: &str| {
+        json.get(key)
+            .and_then(as_u64)
+            .ok_or(format!("expected member.{key} to be a number."))
+    };
+
+    let name = match json.get("name") {
+        None | Some(JsonValue::Null) => None,
+        Some(JsonValue::String(name)) => Some(name.clone()),
+        Some(_) => return Err("expected member.name to be null or string.".into()),
+    };
+
+    let mut completions = BTreeMap::new();
+    let days = json
+        .get("completion_day_level")
+        .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+        .ok_or("expected member.completion_day_level to be an object.")?;
+
+    for (day, parts) in days {
+        let day = Day::from_str(day).map_err(|_| format!("invalid day \"{day}\"."))?;
+        let parts = parts
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected completion_day_level entries to be objects.")?;
+
+        let star = |part: &str| {
+            parts.get(part).map(|p| {
+                p.get::<HashMap<String, JsonValue>>()
+                    .and_then(|p| p.get("get_star_ts"))
+                    .and_then(as_u64)
+                    .and_then(|ts| i64::try_from(ts).ok())
+                    .ok_or(format!(
+                        "expected day {day} part {part} to have a `get_star_ts`."
+                    ))
+            })
+        };
+
+        completions.insert(day, [star("1").transpose()?, star("2").transpose()?]);
+    }
+
+    Ok(Member {
+        id: number("id")?,
+        name,
+        local_score: number("local_score")?,
+        stars: u32::try_from(number("stars")?).map_err(|e| e.to_string())?,
+        completions,
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Leaderboard {
+    /// Unix timestamp at which the puzzle of `day` unlocked.
+    pub fn unlock_ts(&self, day: Day) -> i64 {
+        day.unlock_ts(self.event)
+    }
+
+    /// Members ordered by local score, then by stars and by who got their last star first.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by_key(|m| {
+            let last_star = m.completions.values().flatten().flatten().max().copied();
+            (
+                std::cmp::Reverse(m.local_score),
+                std::cmp::Reverse(m.stars),
+                last_star.unwrap_or(i64::MAX),
+                m.id,
+            )
+        });
+        members
+    }
+
+    /// Days with at least one star, in order.
+    pub fn days(&self) -> Vec<Day> {
+        let mut days: Vec<Day> = self
+            .members
+            .iter()
+            .flat_map(|m| m.completions.keys().copied())
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+}
+
+/// Formats a number of seconds as `H:MM:SS`, prefixed with the number of days if any.
+pub fn format_delta(secs: i64) -> String {
+    let sign = if secs < 0 { "-" } else { "" };
+    let secs = secs.abs();
+    let (days, h, m, s) = (
+        secs / 86400,
+        secs % 86400 / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    );
+    if days > 0 {
+        format!("{sign}{days}d {h:02}:{m:02}:{s:02}")
+    } else {
+        format!("{sign}{h}:{m:02}:{s:02}")
+    }
+}
+
+/// The ranking table with local scores and the stars of every day.
+pub fn format_ranking(leaderboard: &Leaderboard) -> String {
+    let last_day = leaderboard.days().last().map_or(0, |d| d.into_inner());
+    let ranking = leaderboard.ranking();
+    let name_width = ranking
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let mut out = format!(
+        "{ANSI_BOLD}Private leaderboard {}{ANSI_RESET} {ANSI_ITALIC}({} members){ANSI_RESET}\n\n",
+        leaderboard.event,
+        leaderboard.members.len()
+    );
+
+    let _ = write!(out, "  #  Score  Stars  {:name_width$}", "Name");
+    for day in 1..=last_day {
+        let _ = write!(out, " {:>2}", day);
+    }
+    out.push('\n');
+
+    for (rank, member) in ranking.iter().enumerate() {
+        let _ = write!(
+            out,
+            "{:>3}  {:>5}  {:>5}  {:name_width$}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            member.display_name()
+        );
+        for day in (1..=last_day).filter_map(Day::new) {
+            let stars = match member.completions.get(&day) {
+                Some([Some(_), Some(_)]) => "★",
+                Some([Some(_), None]) => "☆",
+                _ => "·",
+            };
+            let _ = write!(out, "  {stars}");
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Completion times of a day relative to its unlock, ordered by time to the second star.
+pub fn format_day(leaderboard: &Leaderboard, day: Day) -> String {
+    let unlock = leaderboard.unlock_ts(day);
+    let mut members: Vec<&Member> = leaderboard
+        .members
+        .iter()
+        .filter(|m| m.star_ts(day, 0).is_some())
+        .collect();
+    members.sort_by_key(|m| {
+        (
+            m.star_ts(day, 1).unwrap_or(i64::MAX),
+            m.star_ts(day, 0),
+            m.id,
+        )
+    });
+
+    let name_width = members
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let mut out = format!("{ANSI_BOLD}Day {day}{ANSI_RESET}\n");
+    let _ = writeln!(
+        out,
+        "  {:name_width$}  {:>12}  {:>12}  {:>12}",
+        "Name", "Part 1", "Part 2", "Delta"
+    );
+
+    for member in members {
+        let first = member.star_ts(day, 0);
+        let second = member.star_ts(day, 1);
+        let relative = |ts: Option<i64>| ts.map_or("-".into(), |ts| format_delta(ts - unlock));
+        let delta = match (first, second) {
+            (Some(first), Some(second)) => format!("+{}", format_delta(second - first)),
+            _ => "-".into(),
+        };
+
+        let _ = writeln!(
+            out,
+            "  {:name_width$}  {:>12}  {:>12}  {:>12}",
+            member.display_name(),
+            relative(first),
+            relative(second),
+            delta
+        );
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_day, format_delta, format_ranking, Leaderboard};
+    use crate::day;
+
+    // 2024-12-01 05:00:00 UTC
+    const DAY_1: i64 = 1_733_029_200;
+
+    fn get_mock_leaderboard() -> Leaderboard {
+        format!(
+            r#"{{
+                "event": "2024",
+                "owner_id": 1,
+                "members": {{
+                    "1": {{
+                        "id": 1, "name": "Alice", "stars": 3, "local_score": 11, "global_score": 0,
+                        "last_star_ts": {d2p1},
+                        "completion_day_level": {{
+                            "1": {{ "1": {{ "get_star_ts": {d1p1}, "star_index": 1 }}, "2": {{ "get_star_ts": {d1p2}, "star_index": 2 }} }},
+                            "2": {{ "1": {{ "get_star_ts": {d2p1}, "star_index": 5 }} }}
+                        }}
+                    }},
+                    "2": {{
+                        "id": 2, "name": null, "stars": 2, "local_score": 11, "global_score": 0,
+                        "last_star_ts": {d1p2b},
+                        "completion_day_level": {{
+                            "1": {{ "1": {{ "get_star_ts": {d1p1b}, "star_index": 3 }}, "2": {{ "get_star_ts": {d1p2b}, "star_index": 4 }} }}
+                        }}
+                    }},
+                    "3": {{
+                        "id": 3, "name": "Carol", "stars": 0, "local_score": 0, "global_score": 0,
+                        "last_star_ts": 0, "completion_day_level": {{}}
+                    }}
+                }}
+            }}"#,
+            d1p1 = DAY_1 + 300,
+            d1p2 = DAY_1 + 600,
+            d1p1b = DAY_1 + 200,
+            d1p2b = DAY_1 + 3900,
+            d2p1 = DAY_1 + 86400 + 4000,
+        )
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = get_mock_leaderboard();
+        assert_eq!(leaderboard.event, 2024);
+        assert_eq!(leaderboard.members.len(), 3);
+        assert_eq!(leaderboard.days(), [day!(1), day!(2)]);
+        assert_eq!(leaderboard.unlock_ts(day!(1)), DAY_1);
+        assert_eq!(leaderboard.unlock_ts(day!(25)), DAY_1 + 24 * 86400);
+    }
+
+    #[test]
+    fn rejects_malformed_leaderboards() {
+        assert!("{}".parse::<Leaderboard>().is_err());
+        assert!(r#"{ "event": "2024", "members": { "1": { "id": 1 } } }"#
+            .parse::<Leaderboard>()
+            .is_err());
+    }
+
+    #[test]
+    fn ranks_members() {
+        let leaderboard = get_mock_leaderboard();
+        let ranking: Vec<u64> = leaderboard.ranking().iter().map(|m| m.id).collect();
+        // equal scores are ranked by stars.
+        assert_eq!(ranking, [1, 2, 3]);
+
+        let table = format_ranking(&leaderboard);
+        assert!(table.contains("  1     11      3  Alice                ★  ☆\n"));
+        assert!(table.contains("  2     11      2  (anonymous user #2)  ★  ·\n"));
+    }
+
+    #[test]
+    fn formats_day_times() {
+        let leaderboard = get_mock_leaderboard();
+        let day = format_day(&leaderboard, day!(1));
+        let lines: Vec<&str> = day.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[2],
+            "  Alice                     0:05:00       0:10:00      +0:05:00"
+        );
+        assert_eq!(
+            lines[3],
+            "  (anonymous user #2)       0:03:20       1:05:00      +1:01:40"
+        );
+    }
+
+    #[test]
+    fn formats_deltas() {
+        assert_eq!(format_delta(59), "0:00:59");
+        assert_eq!(format_delta(86400 + 3661), "1d 01:01:01");
+        assert_eq!(format_delta(-60), "-0:01:00");
+    }
+}
@@ -0,0 +1,168 @@
+/// Leveled debug output for solutions, written with [`aoc_debug!`](crate::aoc_debug) and
+/// [`aoc_trace!`](crate::aoc_trace).
+///
+/// The level is set by passing `-v` (debug) or `-vv` (trace) to `cargo solve`. Messages go to
+/// stderr, so the test harness captures them like any other output, and [`capture`] collects them
+/// for assertions. While the runner benches a part, logging is off and the arguments of the macros
+/// are not evaluated.
+use std::cell::RefCell;
+use std::env;
+use std::fmt::{Arguments, Display};
+use std::sync::OnceLock;
+
+use crate::template::runner::is_benching;
+use crate::template::{ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Debug,
+    Trace,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Level::Off => "off",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl Level {
+    /// Reads the level from `-v` or `-vv` in command-line arguments.
+    pub fn from_args(args: &[String]) -> Self {
+        if args.iter().any(|x| x == "-vv") {
+            Level::Trace
+        } else if args.iter().any(|x| x == "-v" || x == "--verbose") {
+            Level::Debug
+        } else {
+            Level::Off
+        }
+    }
+}
+
+thread_local! {
+    static CAPTURE: RefCell<Option<(Level, Vec<String>)>> = const { RefCell::new(None) };
+}
+
+/// The level requested on the command-line of the solution binary.
+fn requested() -> Level {
+    static LEVEL: OnceLock<Level> = OnceLock::new();
+    *LEVEL.get_or_init(|| Level::from_args(&env::args().collect::<Vec<_>>()))
+}
+
+/// Whether messages of `level` are currently written.
+pub fn enabled(level: Level) -> bool {
+    if level == Level::Off || is_benching() {
+        return false;
+    }
+    let max = CAPTURE
+        .with_borrow(|capture| capture.as_ref().map(|(level, _)| *level))
+        .unwrap_or_else(requested);
+    level <= max
+}
+
+/// Writes a message. Use the macros instead, which skip formatting if `level` is disabled.
+#[doc(hidden)]
+pub fn write(level: Level, args: Arguments) {
+    let message = args.to_string();
+    let captured = CAPTURE.with_borrow_mut(|capture| match capture {
+        Some((_, lines)) => {
+            lines.push(message.clone());
+            true
+        }
+        None => false,
+    });
+    if !captured {
+        eprintln!("{ANSI_ITALIC}[{level}]{ANSI_RESET} {message}");
+    }
+}
+
+/// Runs `func` with logging at `level` on the current thread and returns the logged messages
+/// instead of writing them.
+pub fn capture<T>(level: Level, func: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let previous = CAPTURE.replace(Some((level, vec![])));
+    let result = func();
+    let lines = CAPTURE
+        .replace(previous)
+        .map(|(_, lines)| lines)
+        .unwrap_or_default();
+    (result, lines)
+}
+
+/// Writes a debug message, shown with `cargo solve <day> -v`.
+#[macro_export]
+macro_rules! aoc_debug {
+    ($($arg:tt)*) => {
+        if $crate::template::log::enabled($crate::template::log::Level::Debug) {
+            $crate::template::log::write($crate::template::log::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+/// Writes a trace message, shown with `cargo solve <day> -vv`.
+#[macro_export]
+macro_rules! aoc_trace {
+    ($($arg:tt)*) => {
+        if $crate::template::log::enabled($crate::template::log::Level::Trace) {
+            $crate::template::log::write($crate::template::log::Level::Trace, format_args!($($arg)*));
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{capture, enabled, Level};
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn reads_levels() {
+        assert_eq!(Level::from_args(&args("01 --time")), Level::Off);
+        assert_eq!(Level::from_args(&args("01 -v")), Level::Debug);
+        assert_eq!(Level::from_args(&args("01 -vv --time")), Level::Trace);
+    }
+
+    #[test]
+    fn captures_messages() {
+        let mut evaluated = 0;
+        let (result, lines) = capture(Level::Debug, || {
+            crate::aoc_debug!("found {}", 42);
+            crate::aoc_trace!("step {}", {
+                evaluated += 1;
+                1
+            });
+            7
+        });
+
+        assert_eq!(result, 7);
+        assert_eq!(lines, vec!["found 42"]);
+        assert_eq!(evaluated, 0);
+
+        let (_, lines) = capture(Level::Trace, || crate::aoc_trace!("step"));
+        assert_eq!(lines, vec!["step"]);
+    }
+
+    #[test]
+    fn nests_captures() {
+        let (inner, outer) = capture(Level::Debug, || {
+            crate::aoc_debug!("outer");
+            let (_, inner) = capture(Level::Off, || {
+                assert!(!enabled(Level::Debug));
+                crate::aoc_debug!("hidden");
+            });
+            crate::aoc_debug!("outer again");
+            inner
+        });
+
+        assert!(inner.is_empty());
+        assert_eq!(outer, vec!["outer", "outer again"]);
+    }
+}
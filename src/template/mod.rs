@@ -1,12 +1,31 @@
-use std::{env, fs};
+use std::{fs, io, path::PathBuf};
 
 pub mod aoc_cli;
+pub mod bench;
 pub mod commands;
+pub mod config;
+pub mod day_set;
+pub mod fuzz;
+pub mod input_check;
+pub mod inputs;
+pub mod leaderboard;
+pub mod log;
+pub mod ocr;
+pub mod profiles;
+pub mod puzzle;
+pub mod report;
 pub mod runner;
+pub mod splits;
+pub mod variants;
+pub mod visualize;
 
+pub use config::Config;
 pub use day::*;
+pub use errors::*;
 
+mod answers;
 mod day;
+mod errors;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
@@ -15,43 +34,138 @@ pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+fn read_path(path: PathBuf) -> Result<String, ReadFileError> {
+    fs::read_to_string(&path).map_err(|source| ReadFileError { path, source })
+}
+
 /// Helper function that reads a text file to a string.
+///
+/// # Panics
+/// Panics with the path of the file if it can't be read. See [`try_read_file`] for a non-panicking variant.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    try_read_file(folder, day).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Helper function that reads a text file to a string, returning an error that names the file on failure.
+///
+/// A missing or blank input (e.g. the empty file created by `cargo scaffold`) is decrypted from the
+/// inputs bundle, if it contains the day. See [`inputs`].
+pub fn try_read_file(folder: &str, day: Day) -> Result<String, ReadFileError> {
+    let result = read_path(Config::get().data_dir(folder).join(format!("{day}.txt")));
+    let is_missing = match &result {
+        Ok(input) => input.trim().is_empty(),
+        Err(e) => e.source.kind() == io::ErrorKind::NotFound,
+    };
+    if folder != "inputs" || !is_missing {
+        return result;
+    }
+
+    match inputs::decrypt_input(day) {
+        Ok(Some(input)) => Ok(input),
+        Ok(None) => result,
+        Err(bundle_error) => {
+            let (kind, reason) = match result {
+                Ok(_) => (io::ErrorKind::InvalidData, "is empty"),
+                Err(_) => (io::ErrorKind::NotFound, "is missing"),
+            };
+            Err(ReadFileError {
+                path: Config::get().input_path(day),
+                source: io::Error::new(
+                    kind,
+                    format!("the file {reason} and could not be decrypted: {bundle_error}"),
+                ),
+            })
+        }
+    }
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+///
+/// # Panics
+/// Panics with the path of the file if it can't be read. See [`try_read_file_part`] for a non-panicking variant.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    try_read_file_part(folder, day, part).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Non-panicking variant of [`read_file_part`].
+pub fn try_read_file_part(folder: &str, day: Day, part: u8) -> Result<String, ReadFileError> {
+    read_path(
+        Config::get()
+            .data_dir(folder)
+            .join(format!("{day}-{part}.txt")),
+    )
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Alternatively, the day can be followed by a list of `key: value` options:
+///  - `fuzz: <Fuzzer>` registers a [`fuzz::Fuzzer`] that is run by `cargo fuzz-day`.
+///  - `parse: <fn(&str) -> T>` replaces the `parse` function of the day's module as the parse step
+///    of `cargo bench`.
+///  - `variants: [<Variant>, ...]` registers alternative implementations as [`variants::Variant`]s,
+///    which are run by `cargo solve --variant <name>` and compared by `cargo time --variants`.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, {}, [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, {}, [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, {}, [part_two, 2]);
+    };
+    ($day:expr $(, $key:ident: $value:expr)+ $(,)?) => {
+        $( $crate::solution!(@option $key); )+
+        $crate::solution!(@impl $day, { $($key: $value),+ }, [part_one, 1] [part_two, 2]);
+    };
+
+    (@option fuzz) => {};
+    (@option parse) => {};
+    (@option variants) => {};
+    (@option $other:ident) => {
+        compile_error!(concat!("unknown `solution!` option `", stringify!($other), "`"));
+    };
+
+    (@fuzz {}) => {
+        eprintln!("Day {DAY} does not register a fuzzer. Pass one to `solution!` with `fuzz: <Fuzzer>`.");
+        std::process::exit(1);
+    };
+    (@fuzz { fuzz: $fuzzer:expr $(, $key:ident: $value:expr)* }) => {
+        $crate::template::fuzz::run(&$fuzzer, DAY);
+        return;
+    };
+    (@fuzz { $_key:ident: $_value:expr $(, $key:ident: $value:expr)* }) => {
+        $crate::solution!(@fuzz { $($key: $value),* })
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@variants {} $request:ident, $input:ident) => {
+        eprintln!("Day {DAY} does not register variants. Pass them to `solution!` with `variants: [<Variant>, ...]`.");
+        std::process::exit(1);
+    };
+    (@variants { variants: $variants:expr $(, $key:ident: $value:expr)* } $request:ident, $input:ident) => {
+        $crate::template::variants::run(&$variants, &$request, &$input, DAY);
+        return;
+    };
+    (@variants { $_key:ident: $_value:expr $(, $key:ident: $value:expr)* } $request:ident, $input:ident) => {
+        $crate::solution!(@variants { $($key: $value),* } $request, $input)
+    };
+
+    (@parse $bench:ident {}) => {
+        $bench.step("parse", parse)
+    };
+    (@parse $bench:ident { parse: $parse:expr $(, $key:ident: $value:expr)* }) => {
+        $bench.step("parse", $parse)
+    };
+    (@parse $bench:ident { $_key:ident: $_value:expr $(, $key:ident: $value:expr)* }) => {
+        $crate::solution!(@parse $bench { $($key: $value),* })
+    };
+
+    (@impl $day:expr, { $($key:ident: $value:expr),* }, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -61,8 +175,34 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
+            if $crate::template::fuzz::is_requested() {
+                $crate::solution!(@fuzz { $($key: $value),* });
+            }
+            let input = match $crate::template::try_read_file("inputs", DAY) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            };
+            $crate::template::input_check::check_or_exit(
+                DAY,
+                &$crate::template::Config::get().input_path(DAY),
+                &input,
+            );
+            if let Some(request) = $crate::template::variants::requested() {
+                $crate::solution!(@variants { $($key: $value),* } request, input);
+            }
             $( run_part($func, &input, DAY, $part); )*
         }
+
+        /// Registers the steps of this solution with the `cargo bench` harness.
+        #[doc(hidden)]
+        #[allow(dead_code)]
+        pub fn __bench() -> $crate::template::bench::DayBench {
+            let bench = $crate::template::bench::DayBench::new(DAY);
+            let bench = $crate::solution!(@parse bench { $($key: $value),* });
+            bench $( .step(stringify!($func), $func) )*
+        }
     };
 }
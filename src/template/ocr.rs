@@ -0,0 +1,287 @@
+/// Decodes answers drawn as letters on a grid, using the glyph alphabets of Advent of Code.
+///
+/// Two fonts are supported: the small font with glyphs 6 pixels high (e.g. 2016 day 8, 2019 day 8)
+/// and the large font with glyphs 10 pixels high (e.g. 2018 day 10).
+/// Glyphs are separated by columns without lit pixels, so their width may vary.
+use std::collections::HashSet;
+use std::fmt::Display;
+
+const SMALL_FONT: &[(char, &str)] = &[
+    ('A', ".##.|#..#|#..#|####|#..#|#..#"),
+    ('B', "###.|#..#|###.|#..#|#..#|###."),
+    ('C', ".##.|#..#|#...|#...|#..#|.##."),
+    ('E', "####|#...|###.|#...|#...|####"),
+    ('F', "####|#...|###.|#...|#...|#..."),
+    ('G', ".##.|#..#|#...|#.##|#..#|.###"),
+    ('H', "#..#|#..#|####|#..#|#..#|#..#"),
+    ('I', "###|.#.|.#.|.#.|.#.|###"),
+    ('J', "..##|...#|...#|...#|#..#|.##."),
+    ('K', "#..#|#.#.|##..|#.#.|#.#.|#..#"),
+    ('L', "#...|#...|#...|#...|#...|####"),
+    ('O', ".##.|#..#|#..#|#..#|#..#|.##."),
+    ('P', "###.|#..#|#..#|###.|#...|#..."),
+    ('R', "###.|#..#|#..#|###.|#.#.|#..#"),
+    ('S', ".###|#...|#...|.##.|...#|###."),
+    ('U', "#..#|#..#|#..#|#..#|#..#|.##."),
+    ('Y', "#...#|#...#|.#.#.|..#..|..#..|..#.."),
+    ('Z', "####|...#|..#.|.#..|#...|####"),
+];
+
+#[rustfmt::skip]
+const LARGE_FONT: &[(char, &str)] = &[
+    ('A', "..##..|.#..#.|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#"),
+    ('B', "#####.|#....#|#....#|#....#|#####.|#....#|#....#|#....#|#....#|#####."),
+    ('C', ".####.|#....#|#.....|#.....|#.....|#.....|#.....|#.....|#....#|.####."),
+    ('E', "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|######"),
+    ('F', "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|#....."),
+    ('G', ".####.|#....#|#.....|#.....|#.....|#..###|#....#|#....#|#...##|.###.#"),
+    ('H', "#....#|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#|#....#"),
+    ('J', "...###|....#.|....#.|....#.|....#.|....#.|....#.|#...#.|#...#.|.###.."),
+    ('K', "#....#|#...#.|#..#..|#.#...|##....|##....|#.#...|#..#..|#...#.|#....#"),
+    ('L', "#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|######"),
+    ('N', "#....#|##...#|##...#|#.#..#|#.#..#|#..#.#|#..#.#|#...##|#...##|#....#"),
+    ('P', "#####.|#....#|#....#|#....#|#####.|#.....|#.....|#.....|#.....|#....."),
+    ('R', "#####.|#....#|#....#|#....#|#####.|#..#..|#...#.|#...#.|#....#|#....#"),
+    ('X', "#....#|#....#|.#..#.|.#..#.|..##..|..##..|.#..#.|.#..#.|#....#|#....#"),
+    ('Z', "######|.....#|.....#|....#.|...#..|..#...|.#....|#.....|#.....|######"),
+];
+
+/// Characters that count as unlit pixels when decoding a rendered grid.
+const UNLIT: &[char] = &['.', ' ', '·', '░', '⬛', '⬜', '🟩'];
+
+/// An error returned when the drawing could not be fully decoded.
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// There are no lit pixels.
+    Empty,
+    /// The drawing is neither 6 nor 10 pixels high.
+    UnsupportedHeight(usize),
+    /// Some glyphs did not match the font. `partial` holds the decoded text with `?` in their place.
+    UnknownGlyphs {
+        partial: String,
+        glyphs: Vec<UnknownGlyph>,
+    },
+}
+
+/// A glyph that is not part of the font, rendered with `#` and `.`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// Position of the glyph within the decoded text.
+    pub index: usize,
+    pub rendering: String,
+}
+
+impl std::error::Error for OcrError {}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Empty => write!(f, "no lit pixels to decode."),
+            OcrError::UnsupportedHeight(height) => write!(
+                f,
+                "letters are {height} pixels high, expected 6 or 10 pixels."
+            ),
+            OcrError::UnknownGlyphs { partial, glyphs } => {
+                write!(
+                    f,
+                    "could not recognize {} glyph(s) in \"{partial}\"",
+                    glyphs.len()
+                )?;
+                for glyph in glyphs {
+                    write!(f, "\nglyph {}:\n{}", glyph.index + 1, glyph.rendering)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Decodes letters drawn by a set of lit `(x, y)` points. Coordinates may be offset arbitrarily.
+pub fn decode_points<I>(points: I) -> Result<String, OcrError>
+where
+    I: IntoIterator<Item = (i64, i64)>,
+{
+    let points: HashSet<(i64, i64)> = points.into_iter().collect();
+
+    let min_x = points.iter().map(|p| p.0).min().ok_or(OcrError::Empty)?;
+    let max_x = points.iter().map(|p| p.0).max().ok_or(OcrError::Empty)?;
+    let min_y = points.iter().map(|p| p.1).min().ok_or(OcrError::Empty)?;
+    let max_y = points.iter().map(|p| p.1).max().ok_or(OcrError::Empty)?;
+
+    let rows: Vec<Vec<bool>> = (min_y..=max_y)
+        .map(|y| (min_x..=max_x).map(|x| points.contains(&(x, y))).collect())
+        .collect();
+
+    decode_rows(&rows)
+}
+
+/// Decodes letters drawn on a rendered grid, e.g. the output of a `println!` visualization.
+///
+/// `#`, `█` and emoji count as lit, `.`, spaces and dark or green squares as unlit.
+pub fn decode_grid(grid: &str) -> Result<String, OcrError> {
+    decode_grid_with(grid, |c| !UNLIT.contains(&c))
+}
+
+/// Decodes letters drawn on a rendered grid, using `is_lit` to tell lit from unlit cells.
+pub fn decode_grid_with(grid: &str, is_lit: impl Fn(char) -> bool) -> Result<String, OcrError> {
+    decode_points(grid.lines().enumerate().flat_map(|(y, line)| {
+        let is_lit = &is_lit;
+        line.chars()
+            .enumerate()
+            .filter(move |(_, c)| is_lit(*c))
+            .map(move |(x, _)| (x as i64, y as i64))
+    }))
+}
+
+fn decode_rows(rows: &[Vec<bool>]) -> Result<String, OcrError> {
+    let font = match rows.len() {
+        6 => SMALL_FONT,
+        10 => LARGE_FONT,
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let width = rows.first().map_or(0, Vec::len);
+    let is_blank_column = |x: usize| rows.iter().all(|row| !row[x]);
+
+    let mut text = String::new();
+    let mut unknown = vec![];
+    let mut x = 0;
+
+    while x < width {
+        if is_blank_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !is_blank_column(x) {
+            x += 1;
+        }
+
+        let rendering = rows
+            .iter()
+            .map(|row| {
+                row[start..x]
+                    .iter()
+                    .map(|&lit| if lit { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let key = rendering.replace('\n', "|");
+        match font.iter().find(|(_, glyph)| *glyph == key) {
+            Some((letter, _)) => text.push(*letter),
+            None => {
+                unknown.push(UnknownGlyph {
+                    index: text.chars().count(),
+                    rendering,
+                });
+                text.push('?');
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::UnknownGlyphs {
+            partial: text,
+            glyphs: unknown,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_grid, decode_points, OcrError, LARGE_FONT, SMALL_FONT};
+
+    /// Renders `text` with `font`, separating glyphs by `gap` blank columns.
+    fn render(font: &[(char, &str)], text: &str, gap: usize) -> String {
+        let glyphs: Vec<Vec<&str>> = text
+            .chars()
+            .map(|c| {
+                font.iter()
+                    .find(|(l, _)| *l == c)
+                    .unwrap()
+                    .1
+                    .split('|')
+                    .collect()
+            })
+            .collect();
+
+        (0..glyphs[0].len())
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|g| g[y])
+                    .collect::<Vec<_>>()
+                    .join(&".".repeat(gap))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn decodes_every_small_glyph() {
+        let text: String = SMALL_FONT.iter().map(|(c, _)| c).collect();
+        assert_eq!(decode_grid(&render(SMALL_FONT, &text, 1)), Ok(text));
+    }
+
+    #[test]
+    fn decodes_every_large_glyph() {
+        let text: String = LARGE_FONT.iter().map(|(c, _)| c).collect();
+        assert_eq!(decode_grid(&render(LARGE_FONT, &text, 2)), Ok(text));
+    }
+
+    #[test]
+    fn decodes_offset_points() {
+        let points = render(SMALL_FONT, "HI", 1)
+            .lines()
+            .enumerate()
+            .flat_map(|(y, l)| {
+                l.chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(x, _)| (x as i64 - 50, y as i64 + 7))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(decode_points(points), Ok("HI".into()));
+    }
+
+    #[test]
+    fn decodes_emoji_art() {
+        let grid = render(SMALL_FONT, "OK", 1)
+            .replace('#', "🤖")
+            .replace('.', "🟩");
+        assert_eq!(decode_grid(&grid), Ok("OK".into()));
+    }
+
+    #[test]
+    fn reports_unknown_glyphs() {
+        let grid = render(SMALL_FONT, "AB", 1)
+            .lines()
+            .map(|l| format!("{l}.#"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match decode_grid(&grid) {
+            Err(OcrError::UnknownGlyphs { partial, glyphs }) => {
+                assert_eq!(partial, "AB?");
+                assert_eq!(glyphs.len(), 1);
+                assert_eq!(glyphs[0].index, 2);
+                assert_eq!(glyphs[0].rendering, "#\n#\n#\n#\n#\n#");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_unsupported_heights() {
+        assert_eq!(decode_grid("#\n#"), Err(OcrError::UnsupportedHeight(2)));
+        assert_eq!(decode_grid("..."), Err(OcrError::Empty));
+    }
+}
@@ -0,0 +1,274 @@
+/// Build profiles compared by `cargo time --profiles`.
+///
+/// `release-native`, `release-lto` and `release-cgu1` are defined in `Cargo.toml`. Profiles whose
+/// name ends in `-native` are built with `-C target-cpu=native`, as cargo profiles cannot set
+/// rustflags on stable.
+use std::env;
+use std::fmt::{Display, Write as _};
+use std::process::{Command, ExitStatus, Stdio};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::template::run_multi::child_commands::parse_nanos;
+use crate::template::timings::Timings;
+use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+}
+
+impl Profile {
+    pub fn release() -> Self {
+        Self {
+            name: "release".into(),
+        }
+    }
+
+    pub fn dev() -> Self {
+        Self { name: "dev".into() }
+    }
+
+    /// The arguments selecting this profile in `cargo build` or `cargo run`.
+    pub fn cargo_args(&self) -> Vec<String> {
+        match self.name.as_str() {
+            "dev" => vec![],
+            "release" => vec!["--release".into()],
+            name => vec!["--profile".into(), name.into()],
+        }
+    }
+
+    /// The `RUSTFLAGS` to build with, if they differ from the environment.
+    pub fn rustflags(&self) -> Option<String> {
+        if !self.name.ends_with("-native") {
+            return None;
+        }
+        let flags = env::var("RUSTFLAGS").unwrap_or_default();
+        Some(format!("{flags} -C target-cpu=native").trim().to_string())
+    }
+
+    /// Creates a `cargo <subcommand>` invocation for this profile.
+    pub fn cargo(&self, subcommand: &str) -> Command {
+        let mut cmd = Command::new("cargo");
+        cmd.arg(subcommand).args(self.cargo_args());
+        if let Some(flags) = self.rustflags() {
+            cmd.env("RUSTFLAGS", flags);
+        }
+        cmd
+    }
+
+    /// Builds all solution binaries, printing compiler output.
+    pub fn build(&self) -> std::io::Result<ExitStatus> {
+        self.cargo("build")
+            .args(["--quiet", "--bins"])
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl FromStr for Profile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(format!("invalid profile name `{s}`."));
+        }
+        Ok(Self {
+            name: match name {
+                "debug" => "dev".into(),
+                name => name.into(),
+            },
+        })
+    }
+}
+
+/// Parses a comma-separated list of profiles, e.g. `release,release-native`.
+pub fn parse_profiles(s: &str) -> Result<Vec<Profile>, String> {
+    let mut profiles: Vec<Profile> = vec![];
+    for profile in s.split(',').map(str::parse) {
+        let profile = profile?;
+        if profiles.contains(&profile) {
+            return Err(format!("profile `{profile}` is listed twice."));
+        }
+        profiles.push(profile);
+    }
+    Ok(profiles)
+}
+
+/// Formats per-part timings of each profile side by side, highlighting the fastest profile of
+/// every part and of the total.
+pub fn format_matrix(results: &[(Profile, Timings)]) -> String {
+    let mut days: Vec<Day> = results
+        .iter()
+        .flat_map(|(_, timings)| timings.data.iter().map(|t| t.day))
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let widths: Vec<usize> = results
+        .iter()
+        .map(|(profile, _)| profile.name.len().max(10))
+        .collect();
+
+    let mut s = String::new();
+    let _ = write!(s, "{ANSI_BOLD}{:<10}", "Day");
+    for ((profile, _), width) in results.iter().zip(&widths) {
+        let _ = write!(s, "  {:>width$}", profile.name);
+    }
+    let _ = writeln!(s, "{ANSI_RESET}");
+
+    let write_row = |s: &mut String, label: &str, cells: Vec<Option<(String, f64)>>| {
+        let best = cells
+            .iter()
+            .flatten()
+            .map(|(_, nanos)| *nanos)
+            .min_by(f64::total_cmp);
+
+        let _ = write!(s, "{label:<10}");
+        for (cell, width) in cells.iter().zip(&widths) {
+            match cell {
+                Some((text, nanos))
+                    if Some(*nanos) == best && cells.iter().flatten().count() > 1 =>
+                {
+                    let _ = write!(s, "  {ANSI_BOLD}{text:>width$}{ANSI_RESET}");
+                }
+                Some((text, _)) => {
+                    let _ = write!(s, "  {text:>width$}");
+                }
+                None => {
+                    let _ = write!(s, "  {:>width$}", "-");
+                }
+            }
+        }
+        let _ = writeln!(s);
+    };
+
+    for day in days {
+        for part in [1, 2] {
+            let cells = results
+                .iter()
+                .map(|(_, timings)| {
+                    let timing = timings.data.iter().find(|t| t.day == day)?;
+                    let text = if part == 1 {
+                        timing.part_1.as_ref()
+                    } else {
+                        timing.part_2.as_ref()
+                    }?;
+                    Some((text.clone(), parse_nanos(text)?))
+                })
+                .collect();
+            write_row(&mut s, &format!("{day} / {part}"), cells);
+        }
+    }
+
+    let totals = results
+        .iter()
+        .map(|(_, timings)| {
+            let nanos = timings.data.iter().map(|t| t.total_nanos).sum::<f64>();
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let text = format!("{:.1?}", Duration::from_nanos(nanos as u64));
+            (!timings.data.is_empty()).then_some((text, nanos))
+        })
+        .collect();
+    write_row(&mut s, "Total", totals);
+
+    let _ = writeln!(
+        s,
+        "{ANSI_ITALIC}The fastest profile of each row is highlighted.{ANSI_RESET}"
+    );
+    s
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_matrix, parse_profiles, Profile};
+    use crate::template::timings::{Timing, Timings};
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    fn timings(part_1: &str, part_2: Option<&str>, total_nanos: f64) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: crate::day!(1),
+                part_1: Some(part_1.into()),
+                part_2: part_2.map(Into::into),
+                total_nanos,
+            }],
+        }
+    }
+
+    #[test]
+    fn parses_profiles() {
+        let profiles = parse_profiles("release, release-native,debug").unwrap();
+        assert_eq!(
+            profiles.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["release", "release-native", "dev"]
+        );
+        assert!(parse_profiles("release,release").is_err());
+        assert!(parse_profiles("release,").is_err());
+        assert!(parse_profiles("release --foo").is_err());
+    }
+
+    #[test]
+    fn selects_profiles() {
+        assert_eq!(Profile::release().cargo_args(), vec!["--release"]);
+        assert!(Profile::dev().cargo_args().is_empty());
+
+        let native: Profile = "release-native".parse().unwrap();
+        assert_eq!(native.cargo_args(), vec!["--profile", "release-native"]);
+        assert!(native
+            .rustflags()
+            .unwrap()
+            .ends_with("-C target-cpu=native"));
+        assert_eq!(Profile::release().rustflags(), None);
+    }
+
+    #[test]
+    fn formats_matrix() {
+        let matrix = format_matrix(&[
+            (
+                Profile::release(),
+                timings("2.0µs", Some("1.0ms"), 1_002_000.0),
+            ),
+            (
+                "release-lto".parse().unwrap(),
+                timings("1.5µs", Some("1.2ms"), 1_201_500.0),
+            ),
+        ]);
+        let lines: Vec<&str> = matrix.lines().collect();
+
+        assert!(lines[0].contains("release-lto"));
+        assert_eq!(
+            lines[1],
+            format!("01 / 1           2.0µs  {ANSI_BOLD}      1.5µs{ANSI_RESET}")
+        );
+        assert_eq!(
+            lines[2],
+            format!("01 / 2      {ANSI_BOLD}     1.0ms{ANSI_RESET}        1.2ms")
+        );
+        assert!(lines[3].starts_with("Total"));
+        assert!(lines[3].contains(&format!("{ANSI_BOLD}     1.0ms{ANSI_RESET}")));
+    }
+
+    #[test]
+    fn skips_missing_parts() {
+        let matrix = format_matrix(&[(Profile::release(), timings("2.0µs", None, 2_000.0))]);
+        let lines: Vec<&str> = matrix.lines().collect();
+        // a single profile has nothing to compare against.
+        assert_eq!(lines[1], "01 / 1           2.0µs");
+        assert_eq!(lines[2], "01 / 2               -");
+    }
+}
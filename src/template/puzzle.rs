@@ -0,0 +1,374 @@
+/// Renders the puzzle descriptions saved by aoc-cli (`data/puzzles/NN.md`) for the terminal.
+///
+/// Supports the subset of markdown that aoc-cli produces: headings, paragraphs, lists, code blocks
+/// and inline `*emphasis*`, `` `code` `` and links. Emphasis is rendered bold, since Advent of Code
+/// uses it to highlight the important bits of a puzzle.
+use std::env;
+
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+const ANSI_CODE: &str = "\x1b[36m";
+const ANSI_DIM: &str = "\x1b[2m";
+
+const DEFAULT_WIDTH: usize = 80;
+
+/// Delimiters of `[text](url)` links.
+const LINK_TEXT_END: [char; 2] = [']', '('];
+const LINK_END: [char; 1] = [')'];
+
+/// The width to wrap paragraphs to, read from `COLUMNS` if set.
+pub fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(DEFAULT_WIDTH)
+        .clamp(40, 100)
+}
+
+/// Splits a puzzle description into part one and, if it has been unlocked, part two.
+pub fn split_parts(markdown: &str) -> (&str, Option<&str>) {
+    let mut offset = 0;
+
+    for line in markdown.split_inclusive('\n') {
+        let text = line.trim_start_matches(['#', '\\', ' ']);
+        if text.starts_with("--- Part Two ---") {
+            return (&markdown[..offset], Some(&markdown[offset..]));
+        }
+        offset += line.len();
+    }
+
+    (markdown, None)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Style {
+    bold: bool,
+    code: bool,
+}
+
+impl Style {
+    fn ansi(self) -> String {
+        let mut s = String::from(ANSI_RESET);
+        if self.bold {
+            s.push_str(ANSI_BOLD);
+        }
+        if self.code {
+            s.push_str(ANSI_CODE);
+        }
+        s
+    }
+}
+
+type StyledText = Vec<(char, Style)>;
+
+/// Renders markdown, wrapping paragraphs and lists to `width` columns.
+pub fn render(markdown: &str, width: usize) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut out: Vec<String> = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+
+    let flush = |paragraph: &mut Vec<&str>, out: &mut Vec<String>| {
+        if !paragraph.is_empty() {
+            let text = parse_inline(&paragraph.join(" "), Style::default());
+            out.extend(wrap(&text, width, "", ""));
+            out.push(String::new());
+            paragraph.clear();
+        }
+    };
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") {
+            flush(&mut paragraph, &mut out);
+            i += 1;
+            while i < lines.len() && !lines[i].trim().starts_with("```") {
+                out.push(format!(
+                    "{ANSI_DIM}│{ANSI_RESET} {ANSI_CODE}{}{ANSI_RESET}",
+                    lines[i]
+                ));
+                i += 1;
+            }
+            out.push(String::new());
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            flush(&mut paragraph, &mut out);
+            push_heading(&mut out, heading.trim_start_matches('#'));
+        } else if is_underline(lines.get(i + 1)) && paragraph.is_empty() && !trimmed.is_empty() {
+            push_heading(&mut out, trimmed);
+            i += 1;
+        } else if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| trimmed.strip_prefix(bullet))
+        {
+            flush(&mut paragraph, &mut out);
+            // list items may continue on indented lines.
+            let mut item = item.to_string();
+            while let Some(next) = lines.get(i + 1).filter(|l| l.starts_with("  ")) {
+                item.push(' ');
+                item.push_str(next.trim());
+                i += 1;
+            }
+            out.extend(wrap(
+                &parse_inline(&item, Style::default()),
+                width,
+                "  • ",
+                "    ",
+            ));
+            if lines.get(i + 1).is_none_or(|l| l.trim().is_empty()) {
+                out.push(String::new());
+            }
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut out);
+        } else {
+            paragraph.push(trimmed);
+        }
+
+        i += 1;
+    }
+
+    flush(&mut paragraph, &mut out);
+
+    while out.last().is_some_and(String::is_empty) {
+        out.pop();
+    }
+
+    let mut rendered = out.join("\n");
+    rendered.push('\n');
+    rendered
+}
+
+fn is_underline(line: Option<&&str>) -> bool {
+    line.map(|l| l.trim()).is_some_and(|l| {
+        l.len() >= 3 && (l.chars().all(|c| c == '-') || l.chars().all(|c| c == '='))
+    })
+}
+
+fn push_heading(out: &mut Vec<String>, heading: &str) {
+    let text = parse_inline(heading.trim(), Style::default());
+    let heading: String = text.iter().map(|(c, _)| c).collect();
+    out.push(format!("{ANSI_BOLD}{heading}{ANSI_RESET}"));
+    out.push(String::new());
+}
+
+/// Parses inline markup into styled characters.
+fn parse_inline(s: &str, base: Style) -> StyledText {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = vec![];
+    let mut style = base;
+    let mut i = 0;
+
+    let find = |from: usize, needle: &[char]| {
+        (from..chars.len()).find(|&j| chars[j..].starts_with(needle))
+    };
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if chars.get(i + 1).is_some_and(char::is_ascii_punctuation) => {
+                out.push((chars[i + 1], style));
+                i += 2;
+            }
+            '`' => {
+                let Some(end) = find(i + 1, &['`']) else {
+                    out.push(('`', style));
+                    i += 1;
+                    continue;
+                };
+                let mut content = &chars[i + 1..end];
+                let mut code_style = Style {
+                    code: true,
+                    ..style
+                };
+                // aoc-cli renders emphasized code as `*code*`.
+                if content.len() > 2 && content[0] == '*' && content[content.len() - 1] == '*' {
+                    content = &content[1..content.len() - 1];
+                    code_style.bold = true;
+                }
+                out.extend(content.iter().map(|c| (*c, code_style)));
+                i = end + 1;
+            }
+            '*' => {
+                let marker: &[char] = if chars.get(i + 1) == Some(&'*') {
+                    &['*', '*']
+                } else {
+                    &['*']
+                };
+                let is_closing = style.bold && style != base;
+                let is_opening = chars
+                    .get(i + marker.len())
+                    .is_some_and(|c| !c.is_whitespace())
+                    && find(i + marker.len(), marker).is_some();
+                if is_closing || is_opening {
+                    style.bold = !style.bold;
+                } else {
+                    out.extend(marker.iter().map(|c| (*c, style)));
+                }
+                i += marker.len();
+            }
+            '[' => {
+                let link = find(i + 1, &LINK_TEXT_END).and_then(|mid| {
+                    let end = find(mid + 2, &LINK_END)?;
+                    Some((mid, end))
+                });
+                match link {
+                    Some((mid, end)) => {
+                        let text: String = chars[i + 1..mid].iter().collect();
+                        out.extend(parse_inline(&text, style));
+                        i = end + 1;
+                    }
+                    None => {
+                        out.push(('[', style));
+                        i += 1;
+                    }
+                }
+            }
+            c => {
+                out.push((c, style));
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+/// Wraps styled text at spaces. `first` and `rest` are the prefixes of the first and later lines.
+fn wrap(text: &StyledText, width: usize, first: &str, rest: &str) -> Vec<String> {
+    let words: Vec<&[(char, Style)]> = text
+        .split(|(c, _)| *c == ' ')
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let mut lines: Vec<Vec<(char, Style)>> = vec![];
+    let mut current: Vec<(char, Style)> = vec![];
+    let mut current_width = 0;
+
+    for word in words {
+        let prefix_width = if lines.is_empty() { first } else { rest }.chars().count();
+        if current_width > 0 && prefix_width + current_width + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+            current_width = 0;
+        }
+        if current_width > 0 {
+            let style = current.last().map(|(_, s)| *s).unwrap_or_default();
+            // do not extend code spans or emphasis across the gap between two words.
+            let gap_style = if word[0].1 == style {
+                style
+            } else {
+                Style::default()
+            };
+            current.push((' ', gap_style));
+            current_width += 1;
+        }
+        current.extend_from_slice(word);
+        current_width += word.len();
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut s = String::from(if i == 0 { first } else { rest });
+            let mut style = Style::default();
+            for (c, char_style) in line {
+                if *char_style != style {
+                    style = *char_style;
+                    s.push_str(&style.ansi());
+                }
+                s.push(*c);
+            }
+            if style != Style::default() {
+                s.push_str(ANSI_RESET);
+            }
+            s
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, split_parts};
+
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| *c == 'm');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    const PUZZLE: &str = r"\--- Day 1: Historian Hysteria ---
+----------
+
+The *Chief Historian* is always present for the big Christmas sleigh launch, but nobody has seen him in months!
+
+```
+3   4
+4   3
+```
+
+Your puzzle answer was `*1234*`.
+
+\--- Part Two ---
+----------
+
+- one item
+- another [item](https://example.com)
+";
+
+    #[test]
+    fn renders_markdown() {
+        let rendered = render(PUZZLE, 40);
+        assert_eq!(
+            strip_ansi(&rendered),
+            "--- Day 1: Historian Hysteria ---
+
+The Chief Historian is always present
+for the big Christmas sleigh launch, but
+nobody has seen him in months!
+
+│ 3   4
+│ 4   3
+
+Your puzzle answer was 1234.
+
+--- Part Two ---
+
+  • one item
+  • another item
+"
+        );
+        assert!(rendered.contains("\x1b[0m\x1b[1mChief Historian\x1b[0m"));
+        assert!(rendered.contains("\x1b[0m\x1b[1m\x1b[36m1234\x1b[0m"));
+    }
+
+    #[test]
+    fn keeps_unmatched_markers() {
+        assert_eq!(
+            strip_ansi(&render("2 * 3 * 4 and `a", 80)),
+            "2 * 3 * 4 and `a\n"
+        );
+    }
+
+    #[test]
+    fn splits_parts() {
+        let (one, two) = split_parts(PUZZLE);
+        assert!(one.ends_with("`*1234*`.\n\n"));
+        assert!(two.unwrap().starts_with("\\--- Part Two ---"));
+        assert_eq!(split_parts("only part one"), ("only part one", None));
+    }
+}
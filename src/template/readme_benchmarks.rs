@@ -3,7 +3,7 @@
 use std::{fs, io};
 
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{Config, Day};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -27,7 +27,8 @@ pub struct TablePosition {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    let path = Config::get().solution_path(day);
+    format!("./{}", path.to_string_lossy().replace('\\', "/"))
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -89,7 +90,7 @@ fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result
 }
 
 pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
+    let path = &Config::get().paths.readme;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
     update_content(&mut readme, timings, total_millis)?;
@@ -0,0 +1,417 @@
+/// Machine-readable reports of `cargo all`, for CI dashboards.
+///
+/// Every part of every day is a test case. Answers are compared to the answers recorded in
+/// `data/answers.json`; days that have not been scaffolded yet are reported as skipped.
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use std::{fmt::Display, fs, io};
+
+use crate::template::answers::Answers;
+use crate::template::run_multi::{DayRun, PartOutcome};
+use crate::template::Day;
+
+/// A report requested with `--report`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// `junit:<path>`
+    Junit(PathBuf),
+    /// `tap` prints to stdout, `tap:<path>` writes to a file.
+    Tap(Option<PathBuf>),
+}
+
+#[derive(Debug)]
+pub struct ReportFormatError(String);
+
+impl std::error::Error for ReportFormatError {}
+
+impl Display for ReportFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid report \"{}\", expected `junit:<path>`, `tap` or `tap:<path>`.",
+            self.0
+        )
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = ReportFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, path) = match s.split_once(':') {
+            Some((kind, path)) if !path.is_empty() => (kind, Some(PathBuf::from(path))),
+            Some(_) => return Err(ReportFormatError(s.into())),
+            None => (s, None),
+        };
+
+        match (kind, path) {
+            ("junit", Some(path)) => Ok(Self::Junit(path)),
+            ("tap", path) => Ok(Self::Tap(path)),
+            _ => Err(ReportFormatError(s.into())),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Passed,
+    Failed(String),
+    Skipped(String),
+}
+
+/// A single part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestCase {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Option<Duration>,
+    pub status: Status,
+    /// `false` if there is no recorded answer to compare to.
+    pub is_verified: bool,
+}
+
+impl TestCase {
+    fn name(&self) -> String {
+        format!("day {} part {}", self.day, self.part)
+    }
+}
+
+/// Compares the outcome of each part with the recorded answers.
+pub fn test_cases(runs: &[DayRun], answers: &Answers) -> Vec<TestCase> {
+    runs.iter()
+        .flat_map(|run| {
+            (1..=2).map(move |part| {
+                let expected = answers.get(run.day, part);
+                let mut case = TestCase {
+                    day: run.day,
+                    part,
+                    answer: None,
+                    duration: None,
+                    status: Status::Passed,
+                    is_verified: expected.is_some(),
+                };
+
+                let Some(parts) = &run.parts else {
+                    case.status = Status::Skipped("not scaffolded.".into());
+                    return case;
+                };
+
+                case.status = match &parts[usize::from(part - 1)] {
+                    PartOutcome::Solved { answer, duration } => {
+                        case.answer = Some(answer.clone());
+                        case.duration = *duration;
+                        match expected {
+                            Some(expected) if expected != answer => Status::Failed(format!(
+                                "expected \"{expected}\", got \"{answer}\"."
+                            )),
+                            _ => Status::Passed,
+                        }
+                    }
+                    PartOutcome::Unsolved => match expected {
+                        Some(expected) => Status::Failed(format!(
+                            "expected \"{expected}\", but the part is not solved."
+                        )),
+                        None => Status::Skipped("not solved.".into()),
+                    },
+                    PartOutcome::Skipped => match expected {
+                        Some(expected) => Status::Failed(format!(
+                            "expected \"{expected}\", but the part is not run."
+                        )),
+                        None => Status::Skipped("not run.".into()),
+                    },
+                    PartOutcome::Failed(message) => Status::Failed(message.clone()),
+                };
+
+                case
+            })
+        })
+        .collect()
+}
+
+pub fn has_failures(cases: &[TestCase]) -> bool {
+    cases.iter().any(|c| matches!(c.status, Status::Failed(_)))
+}
+
+/// Writes a report, printing TAP to stdout if no path was given.
+pub fn write(format: &ReportFormat, cases: &[TestCase]) -> io::Result<()> {
+    match format {
+        ReportFormat::Junit(path) => fs::write(path, to_junit(cases)),
+        ReportFormat::Tap(Some(path)) => fs::write(path, to_tap(cases)),
+        ReportFormat::Tap(None) => {
+            print!("{}", to_tap(cases));
+            Ok(())
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn escape_xml(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_control() || matches!(c, '\n' | '\t'))
+        .fold(String::with_capacity(s.len()), |mut out, c| {
+            match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                '\'' => out.push_str("&apos;"),
+                c => out.push(c),
+            }
+            out
+        })
+}
+
+fn seconds(duration: Option<Duration>) -> f64 {
+    duration.map_or(0.0, |d| d.as_secs_f64())
+}
+
+fn count(cases: &[&TestCase], f: impl Fn(&Status) -> bool) -> usize {
+    cases.iter().filter(|c| f(&c.status)).count()
+}
+
+pub fn to_junit(cases: &[TestCase]) -> String {
+    let all: Vec<&TestCase> = cases.iter().collect();
+    let is_failed = |s: &Status| matches!(s, Status::Failed(_));
+    let is_skipped = |s: &Status| matches!(s, Status::Skipped(_));
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"advent_of_code\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+        all.len(),
+        count(&all, is_failed),
+        count(&all, is_skipped),
+        cases.iter().map(|c| seconds(c.duration)).sum::<f64>()
+    );
+
+    let mut days: Vec<Day> = cases.iter().map(|c| c.day).collect();
+    days.dedup();
+
+    for day in days {
+        let suite: Vec<&TestCase> = cases.iter().filter(|c| c.day == day).collect();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"day{day}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+            suite.len(),
+            count(&suite, is_failed),
+            count(&suite, is_skipped),
+            suite.iter().map(|c| seconds(c.duration)).sum::<f64>()
+        );
+
+        for case in suite {
+            let _ = write!(
+                xml,
+                "    <testcase name=\"part {}\" classname=\"day{day}\" time=\"{:.6}\">",
+                case.part,
+                seconds(case.duration)
+            );
+            match &case.status {
+                Status::Passed => {}
+                Status::Failed(message) => {
+                    let _ = write!(
+                        xml,
+                        "\n      <failure message=\"{}\"/>",
+                        escape_xml(message)
+                    );
+                }
+                Status::Skipped(message) => {
+                    let _ = write!(
+                        xml,
+                        "\n      <skipped message=\"{}\"/>",
+                        escape_xml(message)
+                    );
+                }
+            }
+            if let Some(answer) = &case.answer {
+                let note = if case.is_verified {
+                    ""
+                } else {
+                    " (no recorded answer)"
+                };
+                let _ = write!(
+                    xml,
+                    "\n      <system-out>answer: {}{note}</system-out>",
+                    escape_xml(answer)
+                );
+            }
+            if case.status == Status::Passed && case.answer.is_none() {
+                xml.push_str("</testcase>\n");
+            } else {
+                xml.push_str("\n    </testcase>\n");
+            }
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+pub fn to_tap(cases: &[TestCase]) -> String {
+    let mut tap = format!("1..{}\n", cases.len());
+
+    for (i, case) in cases.iter().enumerate() {
+        let number = i + 1;
+        let name = case.name();
+        let _ = match &case.status {
+            Status::Passed => writeln!(tap, "ok {number} - {name}"),
+            Status::Failed(_) => writeln!(tap, "not ok {number} - {name}"),
+            Status::Skipped(reason) => writeln!(tap, "ok {number} - {name} # SKIP {reason}"),
+        };
+
+        if let Status::Failed(message) = &case.status {
+            message.lines().for_each(|l| _ = writeln!(tap, "# {l}"));
+        }
+        if let Some(answer) = &case.answer {
+            let first_line = answer.lines().next().unwrap_or_default();
+            let ellipsis = if answer.contains('\n') { " ..." } else { "" };
+            let _ = writeln!(tap, "# answer: {first_line}{ellipsis}");
+            if !case.is_verified {
+                let _ = writeln!(tap, "# no recorded answer");
+            }
+        }
+        if let Some(duration) = case.duration {
+            let _ = writeln!(tap, "# duration: {duration:.1?}");
+        }
+    }
+
+    tap
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::{test_cases, to_junit, to_tap, ReportFormat, Status};
+    use crate::day;
+    use crate::template::answers::{Answer, Answers};
+    use crate::template::run_multi::{DayRun, PartOutcome};
+
+    fn solved(answer: &str) -> PartOutcome {
+        PartOutcome::Solved {
+            answer: answer.into(),
+            duration: Some(Duration::from_micros(1500)),
+        }
+    }
+
+    fn get_mock_runs() -> Vec<DayRun> {
+        vec![
+            DayRun {
+                day: day!(1),
+                parts: Some([solved("11"), solved("<31>")]),
+            },
+            DayRun {
+                day: day!(2),
+                parts: Some([
+                    PartOutcome::Failed("bad input".into()),
+                    PartOutcome::Unsolved,
+                ]),
+            },
+            DayRun {
+                day: day!(3),
+                parts: None,
+            },
+        ]
+    }
+
+    fn get_mock_answers() -> Answers {
+        Answers {
+            data: vec![Answer {
+                day: day!(1),
+                part_1: Some("11".into()),
+                part_2: Some("32".into()),
+            }],
+        }
+    }
+
+    #[test]
+    fn parses_report_formats() {
+        assert_eq!(
+            "junit:out/report.xml".parse::<ReportFormat>().unwrap(),
+            ReportFormat::Junit(PathBuf::from("out/report.xml"))
+        );
+        assert_eq!(
+            "tap".parse::<ReportFormat>().unwrap(),
+            ReportFormat::Tap(None)
+        );
+        assert!("junit".parse::<ReportFormat>().is_err());
+        assert!("tap:".parse::<ReportFormat>().is_err());
+        assert!("html:x".parse::<ReportFormat>().is_err());
+    }
+
+    #[test]
+    fn compares_with_recorded_answers() {
+        let cases = test_cases(&get_mock_runs(), &get_mock_answers());
+        let statuses: Vec<&Status> = cases.iter().map(|c| &c.status).collect();
+
+        assert_eq!(
+            statuses,
+            [
+                &Status::Passed,
+                &Status::Failed("expected \"32\", got \"<31>\".".into()),
+                &Status::Failed("bad input".into()),
+                &Status::Skipped("not solved.".into()),
+                &Status::Skipped("not scaffolded.".into()),
+                &Status::Skipped("not scaffolded.".into()),
+            ]
+        );
+        assert!(cases[0].is_verified);
+        assert!(!cases[2].is_verified);
+    }
+
+    #[test]
+    fn skips_parts_that_are_not_run() {
+        let runs = [DayRun {
+            day: day!(25),
+            parts: Some([solved("2=-1=0"), PartOutcome::Skipped]),
+        }];
+        let cases = test_cases(&runs, &Answers::default());
+
+        assert_eq!(cases[1].status, Status::Skipped("not run.".into()));
+        assert!(!super::has_failures(&cases));
+    }
+
+    #[test]
+    fn writes_junit() {
+        let cases = test_cases(&get_mock_runs()[..1], &get_mock_answers());
+        assert_eq!(
+            to_junit(&cases),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="advent_of_code" tests="2" failures="1" skipped="0" time="0.003000">
+  <testsuite name="day01" tests="2" failures="1" skipped="0" time="0.003000">
+    <testcase name="part 1" classname="day01" time="0.001500">
+      <system-out>answer: 11</system-out>
+    </testcase>
+    <testcase name="part 2" classname="day01" time="0.001500">
+      <failure message="expected &quot;32&quot;, got &quot;&lt;31&gt;&quot;."/>
+      <system-out>answer: &lt;31&gt;</system-out>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+
+    #[test]
+    fn writes_tap() {
+        let cases = test_cases(&get_mock_runs()[1..], &Answers::default());
+        assert_eq!(
+            to_tap(&cases),
+            "1..4
+not ok 1 - day 02 part 1
+# bad input
+ok 2 - day 02 part 2 # SKIP not solved.
+ok 3 - day 03 part 1 # SKIP not scaffolded.
+ok 4 - day 03 part 2 # SKIP not scaffolded.
+"
+        );
+    }
+}
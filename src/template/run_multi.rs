@@ -1,14 +1,46 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, io, path::PathBuf, time::Duration};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Config, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// The outcome of a single part, as reported by its solution binary.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PartOutcome {
+    Solved {
+        answer: String,
+        duration: Option<Duration>,
+    },
+    /// The part returned `None`.
+    Unsolved,
+    /// The binary exited successfully without running the part, e.g. the second part of `solution!(25, 1)`.
+    Skipped,
+    /// The part returned an error or the binary did not report a result.
+    Failed(String),
+}
+
+/// The outcome of running a single day.
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub day: Day,
+    /// `None` if the day has not been scaffolded yet.
+    pub parts: Option<[PartOutcome; 2]>,
+}
+
+/// Results collected by [`run_multi`].
+#[derive(Clone, Debug)]
+pub struct MultiRun {
+    pub days: Vec<DayRun>,
+    /// Only present for timed runs.
+    pub timings: Option<Timings>,
+}
+
+pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> MultiRun {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut days: Vec<DayRun> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
 
@@ -24,26 +56,32 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-            if output.is_empty() {
+            if !get_path_for_bin(day).exists() {
                 println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                days.push(DayRun { day, parts: None });
+                return;
             }
+
+            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+
+            let val = child_commands::parse_exec_time(&output.stdout, day);
+            timings.push(val);
+            days.push(DayRun {
+                day,
+                parts: Some(child_commands::parse_part_outcomes(&output)),
+            });
         });
 
-    if is_timed {
+    let timings = is_timed.then(|| {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
-    }
+        timings
+    });
+
+    MultiRun { days, timings }
 }
 
 #[allow(dead_code)]
@@ -60,70 +98,94 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(day: Day) -> PathBuf {
+    Config::get().solution_path(day)
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{get_path_for_bin, Error, PartOutcome};
+    use crate::template::profiles::Profile;
+    use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        process::Stdio,
         thread,
+        time::Duration,
     };
 
+    /// Lines printed by a solution bin.
+    #[derive(Debug, Default)]
+    pub struct SolutionOutput {
+        pub stdout: Vec<String>,
+        pub stderr: Vec<String>,
+        /// Whether the bin exited successfully.
+        pub success: bool,
+    }
+
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<SolutionOutput, Error> {
+        let profile = if is_release {
+            Profile::release()
+        } else {
+            Profile::dev()
+        };
+        run_solution_with_profile(day, is_timed, &profile)
+    }
+
+    /// Run the solution bin for a given day, built with `profile`.
+    pub fn run_solution_with_profile(
+        day: Day,
+        is_timed: bool,
+        profile: &Profile,
+    ) -> Result<SolutionOutput, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !get_path_for_bin(day).exists() {
+            return Ok(SolutionOutput::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
-
-        if is_release {
-            args.push("--release");
-        }
+        let mut cmd = profile.cargo("run");
+        cmd.args(["--quiet", "--bin", &day.to_string()]);
 
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+            cmd.args(["--", "--time"]);
         }
 
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        let mut cmd = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
 
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut output = SolutionOutput::default();
 
         let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+            stderr
+                .lines()
+                .map(|line| {
+                    let line = line.unwrap();
+                    eprintln!("{line}");
+                    line
+                })
+                .collect()
         });
 
         for line in stdout.lines() {
             let line = line.unwrap();
             println!("{line}");
-            output.push(line);
+            output.stdout.push(line);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        output.stderr = thread.join().unwrap();
+        output.success = cmd.wait()?.success();
 
         Ok(output)
     }
@@ -164,6 +226,81 @@ pub mod child_commands {
         timings
     }
 
+    /// Parses the answers printed by a solution binary, e.g. `Part 1: 42 (1.2ms)`.
+    /// Parts without a result are skipped if the bin exited successfully, as it only runs the parts
+    /// passed to `solution!`. Otherwise (e.g. a panic) they fail with the last line of stderr.
+    pub fn parse_part_outcomes(output: &SolutionOutput) -> [PartOutcome; 2] {
+        let missing = || {
+            if output.success {
+                return PartOutcome::Skipped;
+            }
+            PartOutcome::Failed(
+                output
+                    .stderr
+                    .iter()
+                    .rev()
+                    .find(|l| !l.trim().is_empty())
+                    .map_or("no result was reported.".into(), |l| l.trim().to_string()),
+            )
+        };
+        let mut outcomes = [missing(), missing()];
+
+        // intermediate results are overwritten using `\r`, only the final result is relevant.
+        let lines: Vec<&str> = output
+            .stdout
+            .iter()
+            .map(|l| l.rsplit('\r').next().unwrap_or_default())
+            .collect();
+
+        for (i, line) in lines.iter().enumerate() {
+            let Some((index, rest)) = [(0, "Part 1: "), (1, "Part 2: ")]
+                .iter()
+                .find_map(|(index, prefix)| Some((*index, line.strip_prefix(prefix)?)))
+            else {
+                continue;
+            };
+
+            outcomes[index] = if let Some(rest) = rest.strip_prefix(ANSI_BOLD) {
+                let (answer, duration) = rest.split_once(ANSI_RESET).unwrap_or((rest, ""));
+                PartOutcome::Solved {
+                    answer: answer.to_string(),
+                    duration: parse_duration(duration),
+                }
+            } else if let Some(duration) = rest.strip_prefix('▼') {
+                // multi-line answers are printed below the part line.
+                let answer = lines[i + 1..]
+                    .iter()
+                    .take_while(|l| !l.starts_with("Part "))
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                PartOutcome::Solved {
+                    answer: answer.trim_end().to_string(),
+                    duration: parse_duration(duration),
+                }
+            } else {
+                let message = rest
+                    .trim_start_matches('✖')
+                    .replace(ANSI_ITALIC, "")
+                    .replace(ANSI_RESET, "");
+                match message.trim() {
+                    "" => PartOutcome::Unsolved,
+                    message => PartOutcome::Failed(message.to_string()),
+                }
+            };
+        }
+
+        outcomes
+    }
+
+    /// Parses a duration suffix such as ` (1.2ms)` or ` (74.13ns @ 100000 samples)`.
+    fn parse_duration(s: &str) -> Option<Duration> {
+        let str_timing = s.rsplit_once('(')?.1.split([')', '@']).next()?.trim();
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        parse_nanos(str_timing).map(|nanos| Duration::from_nanos(nanos as u64))
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -174,19 +311,22 @@ pub mod child_commands {
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
+        Some((str_timing, parse_nanos(str_timing)?))
+    }
+
+    /// Parses a duration as printed by `{:?}`, e.g. `74.13ns` or `1.2ms`, into nanoseconds.
+    pub fn parse_nanos(str_timing: &str) -> Option<f64> {
+        match str_timing {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
             s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
             s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
             s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,7 +345,10 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::time::Duration;
+
+        use super::{parse_exec_time, parse_part_outcomes, SolutionOutput};
+        use crate::template::run_multi::PartOutcome;
 
         use crate::day;
 
@@ -253,5 +396,108 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        fn stdout(lines: &[String]) -> SolutionOutput {
+            SolutionOutput {
+                stdout: lines.to_vec(),
+                stderr: vec![],
+                success: true,
+            }
+        }
+
+        #[test]
+        fn parses_part_outcomes() {
+            let outcomes = parse_part_outcomes(&stdout(&[
+                "Part 1: \x1b[1m11\x1b[0m > \x1b[3mbenching\x1b[0m\rPart 1: \x1b[1m11\x1b[0m (1.5µs @ 10 samples)".into(),
+                "Part 2: ✖ \x1b[3mdata/inputs/01.txt: line 2: bad\x1b[0m".into(),
+            ]));
+            assert_eq!(
+                outcomes[0],
+                PartOutcome::Solved {
+                    answer: "11".into(),
+                    duration: Some(Duration::from_nanos(1500))
+                }
+            );
+            assert_eq!(
+                outcomes[1],
+                PartOutcome::Failed("data/inputs/01.txt: line 2: bad".into())
+            );
+        }
+
+        #[test]
+        fn parses_multiline_and_missing_outcomes() {
+            let outcomes = parse_part_outcomes(&stdout(&[
+                "Part 1: ▼  (2ms)".into(),
+                "#.#".into(),
+                ".#.".into(),
+                "".into(),
+            ]));
+            assert_eq!(
+                outcomes[0],
+                PartOutcome::Solved {
+                    answer: "#.#\n.#.".into(),
+                    duration: Some(Duration::from_millis(2))
+                }
+            );
+            assert_eq!(outcomes[1], PartOutcome::Skipped);
+
+            let outcomes = parse_part_outcomes(&stdout(&["Part 2: ✖             ".into()]));
+            assert_eq!(outcomes[1], PartOutcome::Unsolved);
+        }
+
+        #[test]
+        fn skips_parts_one_part_days_do_not_run() {
+            // `solution!(25, 1)`
+            let outcomes =
+                parse_part_outcomes(&stdout(&["Part 1: \x1b[1m2=-1=0\x1b[0m (24.1µs)".into()]));
+            assert!(matches!(outcomes[0], PartOutcome::Solved { .. }));
+            assert_eq!(outcomes[1], PartOutcome::Skipped);
+
+            // `solution!(25, 2)`
+            let outcomes =
+                parse_part_outcomes(&stdout(&["Part 2: \x1b[1m7\x1b[0m (1.0ms)".into()]));
+            assert_eq!(outcomes[0], PartOutcome::Skipped);
+        }
+
+        #[test]
+        fn fails_parts_of_panicked_bins() {
+            let output = SolutionOutput {
+                stdout: vec!["Part 1: \x1b[1m42\x1b[0m (1.2ms)".into()],
+                stderr: vec![
+                    "thread 'main' panicked at src/days/day25.rs:10:5:".into(),
+                    "attempt to subtract with overflow".into(),
+                ],
+                success: false,
+            };
+            assert_eq!(
+                parse_part_outcomes(&output)[1],
+                PartOutcome::Failed("attempt to subtract with overflow".into())
+            );
+
+            let output = SolutionOutput {
+                success: false,
+                ..SolutionOutput::default()
+            };
+            assert_eq!(
+                parse_part_outcomes(&output)[0],
+                PartOutcome::Failed("no result was reported.".into())
+            );
+        }
+
+        #[test]
+        fn reports_errors_of_failed_bins() {
+            let output = SolutionOutput {
+                stdout: vec![],
+                stderr: vec![
+                    "Error: could not read \"data/inputs/02.txt\"".into(),
+                    "".into(),
+                ],
+                success: false,
+            };
+            assert_eq!(
+                parse_part_outcomes(&output)[0],
+                PartOutcome::Failed("Error: could not read \"data/inputs/02.txt\"".into())
+            );
+        }
     }
 }
@@ -3,32 +3,73 @@ use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
 use std::process::Output;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::splits::{log_event, EventKind, Verdict};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, Config, Day, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// The return type of a solution part.
+///
+/// Parts can either return an `Option<T>`, where `None` marks an unsolved part,
+/// or a `Result<T, E>` whose error is printed instead of an answer.
+pub trait PartResult {
+    type Answer: Display;
+
+    /// Converts the result into `Ok(None)` for unsolved parts or an error message.
+    fn into_answer(self) -> Result<Option<Self::Answer>, String>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    type Answer = T;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map(Some).map_err(|e| e.to_string())
+    }
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+pub fn run_part<I: Clone, R: PartResult>(func: impl Fn(I) -> R, input: I, day: Day, part: u8) {
+    let part_str = format!("Part {part}");
 
-    if let Some(result) = result {
+    let (result, duration, samples) = run_timed(
+        |input| func(input).into_answer(),
+        input,
+        |result| print_result(result, &part_str, "", day),
+        Result::is_ok,
+    );
+
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&duration, samples),
+        day,
+    );
+
+    if let Ok(Some(result)) = result {
         submit_result(result, day, part);
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched (approx. `bench.budget_ms` of execution time or `bench.min_samples` samples, whatever take longer.)
+///
+/// Results rejected by `should_bench` (e.g. errors) are never benched.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
+    should_bench: impl Fn(&T) -> bool,
 ) -> (T, Duration, u128) {
     let timer = Instant::now();
     let result = {
@@ -43,7 +84,9 @@ fn run_timed<I: Clone, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if should_bench(&result) && std::env::args().any(|x| x == "--time") {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
         bench(func, input, &base_time)
     } else {
         (base_time, 1)
@@ -52,17 +95,26 @@ fn run_timed<I: Clone, T>(
     (result, run.0, run.1)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+static BENCHING: AtomicBool = AtomicBool::new(false);
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+/// Whether the runner is currently benching a part, i.e. calling it repeatedly for timing.
+pub fn is_benching() -> bool {
+    BENCHING.load(Ordering::Relaxed)
+}
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+/// Runs `func` repeatedly for about `bench.budget_ms` and returns the average duration and the number of samples.
+pub(crate) fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (Duration, u128) {
+    let bench = &Config::get().bench;
+    let bench_iterations = (bench.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(bench.min_samples, bench.max_samples);
 
     let mut timers: Vec<Duration> = vec![];
 
+    BENCHING.store(true, Ordering::Relaxed);
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
@@ -70,6 +122,7 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         black_box(func(black_box(cloned)));
         timers.push(timer.elapsed());
     }
+    BENCHING.store(false, Ordering::Relaxed);
 
     (
         #[allow(clippy::cast_possible_truncation)]
@@ -94,11 +147,16 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result<T: Display>(
+    result: &Result<Option<T>, String>,
+    part: &str,
+    duration_str: &str,
+    day: Day,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(Some(result)) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -118,7 +176,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -126,6 +184,15 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(e) => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                let path = Config::get().input_path(day);
+                print!("\r");
+                println!("{part}: ✖ {ANSI_ITALIC}{}: {e}{ANSI_RESET}", path.display());
+            }
+        }
     }
 }
 
@@ -165,5 +232,12 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let output = aoc_cli::submit(day, part, &result.to_string());
+
+    if let Ok(output) | Err(aoc_cli::AocCommandError::BadExitStatus(output)) = &output {
+        let verdict = Verdict::from_output(&String::from_utf8_lossy(&output.stdout));
+        log_event(day, EventKind::Submit { part, verdict });
+    }
+
+    Some(output)
 }
@@ -0,0 +1,354 @@
+/// Records scaffold, read and submit events per day and reports the time it took to get each star.
+///
+/// Events are appended to `paths.events` as one line each: `<unix timestamp> <day> <event>`, where
+/// `<event>` is `scaffold`, `read` or `submit <part> <verdict>`.
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write as _};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::template::leaderboard::format_delta;
+use crate::template::{Config, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// The response of the website to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// The answer was submitted during the timeout after a wrong answer and was not checked.
+    TooSoon,
+    /// The part had been solved before.
+    AlreadySolved,
+    /// The response could not be recognized.
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the output of `aoc submit`.
+    pub fn from_output(output: &str) -> Self {
+        if output.contains("That's the right answer") {
+            Verdict::Correct
+        } else if output.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if output.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if output.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooSoon => "too-soon",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown => "unknown",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "too-soon" => Ok(Verdict::TooSoon),
+            "already-solved" => Ok(Verdict::AlreadySolved),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventKind {
+    Scaffold,
+    Read,
+    Submit { part: u8, verdict: Verdict },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Event {
+    /// Unix timestamp in seconds.
+    pub ts: i64,
+    pub day: Day,
+    pub kind: EventKind,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} ", self.ts, self.day)?;
+        match self.kind {
+            EventKind::Scaffold => write!(f, "scaffold"),
+            EventKind::Read => write!(f, "read"),
+            EventKind::Submit { part, verdict } => write!(f, "submit {part} {verdict}"),
+        }
+    }
+}
+
+impl FromStr for Event {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_whitespace();
+        let mut next = |name: &str| fields.next().ok_or(format!("missing {name}."));
+
+        let ts = next("timestamp")?
+            .parse()
+            .map_err(|_| "expected a unix timestamp.")?;
+        let day = next("day")?.parse().map_err(|_| "expected a day.")?;
+        let kind = match next("event")? {
+            "scaffold" => EventKind::Scaffold,
+            "read" => EventKind::Read,
+            "submit" => {
+                let part = next("part")?
+                    .parse()
+                    .ok()
+                    .filter(|p| *p == 1 || *p == 2)
+                    .ok_or("expected part to be 1 or 2.")?;
+                let verdict = next("verdict")?.parse()?;
+                EventKind::Submit { part, verdict }
+            }
+            event => return Err(format!("unknown event `{event}`.")),
+        };
+
+        Ok(Event { ts, day, kind })
+    }
+}
+
+/// Appends an event for `day` to the event log. Failing to write the log only prints a warning.
+pub fn log_event(day: Day, kind: EventKind) {
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs().try_into().unwrap_or(i64::MAX));
+    let event = Event { ts, day, kind };
+    let path = &Config::get().paths.events;
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| OpenOptions::new().create(true).append(true).open(path))
+        .and_then(|mut file| writeln!(file, "{event}"));
+
+    if let Err(e) = result {
+        eprintln!(
+            "Warning: could not write event log \"{}\": {e}",
+            path.display()
+        );
+    }
+}
+
+/// Parses the contents of an event log. Blank lines are skipped.
+pub fn parse_events(s: &str) -> Result<Vec<Event>, String> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| line.parse().map_err(|e| format!("line {}: {e}", i + 1)))
+        .collect()
+}
+
+/// Progress on one day, derived from its events.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DaySplit {
+    pub first_read: Option<i64>,
+    /// Timestamps of the first correct submission of each part.
+    pub stars: [Option<i64>; 2],
+    pub wrong_submissions: u32,
+}
+
+/// Folds events into splits per day. Events do not need to be ordered.
+pub fn compute_splits(events: &[Event]) -> BTreeMap<Day, DaySplit> {
+    let mut events = events.to_vec();
+    events.sort_by_key(|e| e.ts);
+
+    let mut splits: BTreeMap<Day, DaySplit> = BTreeMap::new();
+
+    for event in events {
+        let split = splits.entry(event.day).or_default();
+        match event.kind {
+            EventKind::Scaffold => {}
+            EventKind::Read => {
+                split.first_read.get_or_insert(event.ts);
+            }
+            EventKind::Submit { part, verdict } => match verdict {
+                Verdict::Correct => {
+                    split.stars[usize::from(part - 1)].get_or_insert(event.ts);
+                }
+                Verdict::Incorrect => split.wrong_submissions += 1,
+                Verdict::TooSoon | Verdict::AlreadySolved | Verdict::Unknown => {}
+            },
+        }
+    }
+
+    splits
+}
+
+/// Formats a table of the time to each star from the unlock and from the first read.
+/// Unlock times are only shown if `year` is known.
+pub fn format_report(events: &[Event], year: Option<u16>) -> String {
+    let splits = compute_splits(events);
+    let mut s = String::new();
+
+    let _ = writeln!(
+        s,
+        "{ANSI_BOLD}{:<5}{:>15}{:>15}{:>15}{:>15}{:>7}{ANSI_RESET}",
+        "Day", "★1 unlock", "★1 read", "★2 unlock", "★2 read", "Wrong"
+    );
+
+    let mut stars = 0;
+    let mut wrong_submissions = 0;
+    let mut time_from_read = 0;
+
+    let since = |from: Option<i64>, to: Option<i64>| match (from, to) {
+        (Some(from), Some(to)) => format_delta(to - from),
+        _ => "-".into(),
+    };
+
+    for (day, split) in &splits {
+        let unlock = year.map(|year| day.unlock_ts(year));
+
+        let _ = writeln!(
+            s,
+            "{:<5}{:>15}{:>15}{:>15}{:>15}{:>7}",
+            day.to_string(),
+            since(unlock, split.stars[0]),
+            since(split.first_read, split.stars[0]),
+            since(unlock, split.stars[1]),
+            since(split.first_read, split.stars[1]),
+            split.wrong_submissions
+        );
+
+        stars += split.stars.iter().flatten().count();
+        wrong_submissions += split.wrong_submissions;
+        if let (Some(read), Some(last)) = (split.first_read, split.stars.iter().flatten().max()) {
+            time_from_read += last - read;
+        }
+    }
+
+    let _ = writeln!(
+        s,
+        "{ANSI_BOLD}Total{ANSI_RESET} {stars} ★ in {} from first read, {wrong_submissions} wrong submissions {ANSI_ITALIC}({} days){ANSI_RESET}",
+        format_delta(time_from_read),
+        splits.len()
+    );
+
+    s
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compute_splits, format_report, parse_events, DaySplit, EventKind, Verdict};
+    use crate::template::Day;
+
+    // 2024-12-01 05:00:00 UTC
+    const UNLOCK_DAY_1: i64 = 1_733_029_200;
+
+    fn log() -> String {
+        let t = UNLOCK_DAY_1;
+        [
+            format!("{} 01 scaffold", t - 600),
+            format!("{} 01 read", t + 30),
+            format!("{} 01 submit 1 incorrect", t + 200),
+            format!("{} 01 submit 1 too-soon", t + 220),
+            format!("{} 01 submit 1 correct", t + 300),
+            String::new(),
+            format!("{} 01 read", t + 310),
+            format!("{} 01 submit 2 correct", t + 400),
+            format!("{} 02 read", t + 86400 + 3600),
+            format!("{} 02 submit 1 correct", t + 86400 + 3900),
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        assert_eq!(
+            Verdict::from_output("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_output("That's not the right answer; your answer is too low."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::from_output("You gave an answer too recently; you have to wait."),
+            Verdict::TooSoon
+        );
+        assert_eq!(Verdict::from_output(""), Verdict::Unknown);
+    }
+
+    #[test]
+    fn parses_events() {
+        let events = parse_events(&log()).unwrap();
+        assert_eq!(events.len(), 9);
+        assert_eq!(events[0].kind, EventKind::Scaffold);
+        assert_eq!(
+            events[2].kind,
+            EventKind::Submit {
+                part: 1,
+                verdict: Verdict::Incorrect
+            }
+        );
+        assert_eq!(
+            events[2].to_string(),
+            format!("{} 01 submit 1 incorrect", UNLOCK_DAY_1 + 200)
+        );
+
+        assert_eq!(
+            parse_events("1 01 read\n1 01 submit 3 correct"),
+            Err("line 2: expected part to be 1 or 2.".into())
+        );
+        assert_eq!(
+            parse_events("1 01 open"),
+            Err("line 1: unknown event `open`.".into())
+        );
+    }
+
+    #[test]
+    fn computes_splits() {
+        let splits = compute_splits(&parse_events(&log()).unwrap());
+        assert_eq!(
+            splits[&Day::new(1).unwrap()],
+            DaySplit {
+                first_read: Some(UNLOCK_DAY_1 + 30),
+                stars: [Some(UNLOCK_DAY_1 + 300), Some(UNLOCK_DAY_1 + 400)],
+                wrong_submissions: 1,
+            }
+        );
+        assert_eq!(splits[&Day::new(2).unwrap()].stars[1], None);
+    }
+
+    #[test]
+    fn formats_report() {
+        let report = format_report(&parse_events(&log()).unwrap(), Some(2024));
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(
+            lines[1],
+            "01           0:05:00        0:04:30        0:06:40        0:06:10      1"
+        );
+        assert_eq!(
+            lines[2],
+            "02           1:05:00        0:05:00              -              -      0"
+        );
+        assert!(lines[3].contains("3 ★ in 0:11:10 from first read, 1 wrong submissions"));
+
+        let report = format_report(&parse_events(&log()).unwrap(), None);
+        assert!(report
+            .lines()
+            .nth(1)
+            .unwrap()
+            .starts_with("01                 -        0:04:30"));
+    }
+}
@@ -0,0 +1,286 @@
+/// Alternative implementations of a day's parts, e.g. a naive and an optimized solution.
+///
+/// A day registers its variants with the `solution!` macro. Running the day binary with
+/// `--variant <name>` then runs a single variant in place of `part_one` and `part_two`, while
+/// `--variants` benches every variant, checks that they agree and prints a comparison table.
+use std::fmt::Write as _;
+use std::io::{stdout, Write};
+use std::time::{Duration, Instant};
+use std::{env, process};
+
+use crate::template::runner::{bench, run_part, PartResult};
+use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// A named implementation of one or both parts.
+pub struct Variant<A, B = A> {
+    pub name: &'static str,
+    pub part_one: Option<fn(&str) -> A>,
+    pub part_two: Option<fn(&str) -> B>,
+}
+
+/// What the command-line of a solution binary asks of its variants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VariantRequest {
+    /// Run a single variant, `--variant <name>`.
+    Run(String),
+    /// Bench and compare all variants, `--variants`.
+    Compare,
+}
+
+/// Reads `--variant <name>` or `--variants` from the command-line.
+pub fn requested() -> Option<VariantRequest> {
+    let args: Vec<String> = env::args().collect();
+
+    if args.iter().any(|x| x == "--variants") {
+        return Some(VariantRequest::Compare);
+    }
+
+    let index = args.iter().position(|x| x == "--variant")?;
+    match args.get(index + 1) {
+        Some(name) => Some(VariantRequest::Run(name.clone())),
+        None => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --variant <name>");
+            process::exit(1);
+        }
+    }
+}
+
+/// Handles a [`VariantRequest`] for the variants of `day`.
+pub fn run<A: PartResult, B: PartResult>(
+    variants: &[Variant<A, B>],
+    request: &VariantRequest,
+    input: &str,
+    day: Day,
+) {
+    match request {
+        VariantRequest::Run(name) => run_variant(variants, name, input, day),
+        VariantRequest::Compare => compare(variants, input, day),
+    }
+}
+
+fn run_variant<A: PartResult, B: PartResult>(
+    variants: &[Variant<A, B>],
+    name: &str,
+    input: &str,
+    day: Day,
+) {
+    let Some(variant) = variants.iter().find(|v| v.name == name) else {
+        eprintln!(
+            "Day {day} has no variant `{name}`. Available variants: {}",
+            format_names(variants)
+        );
+        process::exit(1);
+    };
+
+    println!("{ANSI_ITALIC}Variant {name}{ANSI_RESET}");
+
+    match variant.part_one {
+        Some(func) => run_part(func, input, day, 1),
+        None => println!("Part 1: {ANSI_ITALIC}not implemented by this variant{ANSI_RESET}"),
+    }
+    match variant.part_two {
+        Some(func) => run_part(func, input, day, 2),
+        None => println!("Part 2: {ANSI_ITALIC}not implemented by this variant{ANSI_RESET}"),
+    }
+}
+
+/// The answer and average duration of one variant on one part.
+struct Measurement {
+    answer: String,
+    duration: Duration,
+}
+
+fn measure<R: PartResult>(func: fn(&str) -> R, input: &str) -> Measurement {
+    let timer = Instant::now();
+    let result = func(input).into_answer();
+    let base_time = timer.elapsed();
+
+    let answer = match result {
+        Ok(Some(answer)) => answer.to_string(),
+        Ok(None) => "not solved".into(),
+        Err(e) => {
+            return Measurement {
+                answer: format!("error: {e}"),
+                duration: base_time,
+            }
+        }
+    };
+
+    let (duration, _) = bench(func, input, &base_time);
+    Measurement { answer, duration }
+}
+
+fn compare<A: PartResult, B: PartResult>(variants: &[Variant<A, B>], input: &str, day: Day) {
+    println!(
+        "{ANSI_BOLD}Comparing {} variants of day {day}{ANSI_RESET}",
+        variants.len()
+    );
+
+    let mut results: Vec<[Option<Measurement>; 2]> = vec![];
+
+    for variant in variants {
+        print!("\r{ANSI_ITALIC}benching {}...{ANSI_RESET}", variant.name);
+        let _ = stdout().flush();
+
+        results.push([
+            variant.part_one.map(|func| measure(func, input)),
+            variant.part_two.map(|func| measure(func, input)),
+        ]);
+
+        print!("\r{}\r", " ".repeat(variant.name.len() + 15));
+    }
+
+    let names: Vec<&str> = variants.iter().map(|v| v.name).collect();
+    print!("{}", format_table(&names, &results));
+
+    let mut is_consistent = true;
+    for part in 0..2 {
+        match check_agreement(&names, &results, part) {
+            Ok(Some(answer)) => println!("Part {}: ✔ all variants agree on {answer}", part + 1),
+            Ok(None) => {}
+            Err(message) => {
+                is_consistent = false;
+                println!("Part {}: ✖ variants disagree: {message}", part + 1);
+            }
+        }
+    }
+
+    if !is_consistent {
+        process::exit(1);
+    }
+}
+
+/// Returns the common answer of all variants that implement `part`, or a listing of the answers.
+fn check_agreement(
+    names: &[&str],
+    results: &[[Option<Measurement>; 2]],
+    part: usize,
+) -> Result<Option<String>, String> {
+    let answers: Vec<(&str, &str)> = names
+        .iter()
+        .zip(results)
+        .filter_map(|(name, r)| r[part].as_ref().map(|m| (*name, m.answer.as_str())))
+        .collect();
+
+    match answers.first() {
+        None => Ok(None),
+        Some((_, first)) if answers.iter().all(|(_, a)| a == first) => {
+            Ok(Some((*first).to_string()))
+        }
+        Some(_) => Err(answers
+            .iter()
+            .map(|(name, answer)| format!("{name} = {answer}"))
+            .collect::<Vec<_>>()
+            .join(", ")),
+    }
+}
+
+/// Formats the durations of every variant, highlighting the fastest one per part and showing how
+/// much slower the others are.
+fn format_table(names: &[&str], results: &[[Option<Measurement>; 2]]) -> String {
+    let name_width = names.iter().map(|n| n.len()).max().unwrap_or(0).max(7);
+    let mut s = String::new();
+
+    let _ = writeln!(
+        s,
+        "{ANSI_BOLD}{:<name_width$}{:>22}{:>22}{ANSI_RESET}",
+        "Variant", "Part 1", "Part 2"
+    );
+
+    let fastest: [Option<Duration>; 2] = [0, 1].map(|part| {
+        results
+            .iter()
+            .filter_map(|r| r[part].as_ref().map(|m| m.duration))
+            .min()
+    });
+
+    for (name, result) in names.iter().zip(results) {
+        let _ = write!(s, "{name:<name_width$}");
+        for part in 0..2 {
+            let cell = match (&result[part], fastest[part]) {
+                (Some(m), Some(fastest)) if m.duration == fastest => {
+                    format!(
+                        "{ANSI_BOLD}{:>22}{ANSI_RESET}",
+                        format!("{:.1?}", m.duration)
+                    )
+                }
+                (Some(m), Some(fastest)) => {
+                    let factor = m.duration.as_secs_f64() / fastest.as_secs_f64().max(1e-9);
+                    format!("{:>22}", format!("{:.1?} ({factor:.1}x)", m.duration))
+                }
+                _ => format!("{:>22}", "-"),
+            };
+            s.push_str(&cell);
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+fn format_names<A, B>(variants: &[Variant<A, B>]) -> String {
+    variants
+        .iter()
+        .map(|v| v.name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{check_agreement, format_table, Measurement};
+
+    fn measurement(answer: &str, micros: u64) -> Option<Measurement> {
+        Some(Measurement {
+            answer: answer.into(),
+            duration: Duration::from_micros(micros),
+        })
+    }
+
+    fn strip_ansi(s: &str) -> String {
+        s.replace("\x1b[1m", "").replace("\x1b[0m", "")
+    }
+
+    #[test]
+    fn checks_agreement() {
+        let names = ["naive", "cramer"];
+        let results = [
+            [measurement("480", 100), None],
+            [measurement("480", 10), measurement("875", 20)],
+        ];
+        assert_eq!(check_agreement(&names, &results, 0), Ok(Some("480".into())));
+        assert_eq!(check_agreement(&names, &results, 1), Ok(Some("875".into())));
+
+        let results = [
+            [measurement("480", 100), None],
+            [measurement("481", 10), None],
+        ];
+        assert_eq!(
+            check_agreement(&names, &results, 0),
+            Err("naive = 480, cramer = 481".into())
+        );
+        assert_eq!(check_agreement(&names, &results, 1), Ok(None));
+    }
+
+    #[test]
+    fn formats_comparison_table() {
+        let names = ["naive", "cramer"];
+        let results = [
+            [measurement("480", 100), None],
+            [measurement("480", 10), measurement("875", 20)],
+        ];
+        let table = format_table(&names, &results);
+        assert_eq!(
+            strip_ansi(&table),
+            "Variant                Part 1                Part 2
+naive         100.0µs (10.0x)                     -
+cramer                 10.0µs                20.0µs
+"
+        );
+        assert!(table.contains("\x1b[1m                10.0µs\x1b[0m"));
+    }
+}
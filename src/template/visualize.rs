@@ -0,0 +1,693 @@
+/// Records frames of grid simulations and plays them back in the terminal or exports them as images.
+///
+/// A solution creates a [`Recorder`] with a [`Palette`] and pushes [`Frame`]s while it runs.
+/// Recording is enabled by `cargo solve <day> --visualize <terminal|gif|png|ppm>`, which builds
+/// the day with the `visualize` feature. Otherwise, and during bench iterations, the recorder is
+/// inactive and never calls the closures that build frames, so solutions can keep their recording
+/// code without slowing down benchmarks.
+use std::fmt::{Display, Write as _};
+use std::fs;
+use std::io::{self, stdout, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use std::{env, process, thread};
+
+use crate::template::runner::is_benching;
+use crate::template::{Config, Day, ANSI_ITALIC, ANSI_RESET};
+
+/// Recordings are thinned out to at most this many frames, keeping every second frame each time.
+pub const MAX_FRAMES: usize = 1000;
+
+pub const DEFAULT_FPS: u32 = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    pub const BLACK: Color = Color::rgb(15, 15, 35);
+    pub const GRAY: Color = Color::rgb(110, 110, 120);
+    pub const WHITE: Color = Color::rgb(230, 230, 230);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(0, 153, 0);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const YELLOW: Color = Color::rgb(255, 255, 102);
+    pub const ORANGE: Color = Color::rgb(203, 75, 22);
+}
+
+struct PaletteEntry {
+    cell: char,
+    color: Color,
+    label: String,
+}
+
+/// Maps the cells of frames to colors. Cells without an entry are drawn in the background color.
+pub struct Palette {
+    background: Color,
+    entries: Vec<PaletteEntry>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            background: Color::BLACK,
+            entries: vec![],
+        }
+    }
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// Draws `cell` in `color` and lists it as `label` in the legend.
+    ///
+    /// # Panics
+    /// Panics if the palette already has 255 entries, as GIFs are limited to 256 colors.
+    pub fn with(mut self, cell: char, color: Color, label: &str) -> Self {
+        assert!(
+            self.entries.len() < 255,
+            "a palette has at most 255 entries"
+        );
+        self.entries.retain(|e| e.cell != cell);
+        self.entries.push(PaletteEntry {
+            cell,
+            color,
+            label: label.to_string(),
+        });
+        self
+    }
+
+    /// The index of `cell`'s color in [`Palette::colors`].
+    fn index(&self, cell: char) -> u8 {
+        self.entries
+            .iter()
+            .position(|e| e.cell == cell)
+            .map_or(0, |i| u8::try_from(i + 1).unwrap())
+    }
+
+    /// The background color followed by the color of each entry.
+    pub fn colors(&self) -> Vec<Color> {
+        std::iter::once(self.background)
+            .chain(self.entries.iter().map(|e| e.color))
+            .collect()
+    }
+
+    /// Formats the entries as colored `<cells> <label>` pairs. Cells sharing a label are listed together.
+    pub fn legend(&self) -> String {
+        let mut labels: Vec<(&str, String)> = vec![];
+        for e in &self.entries {
+            let cell = fg(e.color, e.cell);
+            match labels.iter_mut().find(|(label, _)| *label == e.label) {
+                Some((_, cells)) => cells.push_str(&cell),
+                None => labels.push((&e.label, cell)),
+            }
+        }
+        labels
+            .iter()
+            .map(|(label, cells)| format!("{cells} {label}"))
+            .collect::<Vec<_>>()
+            .join("   ")
+    }
+}
+
+fn fg(color: Color, s: impl Display) -> String {
+    format!(
+        "\x1b[38;2;{};{};{}m{s}{ANSI_RESET}",
+        color.r, color.g, color.b
+    )
+}
+
+/// A grid of cells with an optional caption.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<char>,
+    pub caption: String,
+}
+
+impl Frame {
+    /// Creates a frame by calling `cell(x, y)` for each position.
+    pub fn from_fn(width: usize, height: usize, cell: impl Fn(usize, usize) -> char) -> Self {
+        Self {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| cell(x, y))
+                .collect(),
+            caption: String::new(),
+        }
+    }
+
+    /// Creates a frame from lines of text. Shorter lines are padded with spaces.
+    pub fn parse(s: &str) -> Self {
+        let lines: Vec<Vec<char>> = s.lines().map(|l| l.chars().collect()).collect();
+        let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+        Self::from_fn(width, lines.len(), |x, y| {
+            lines[y].get(x).copied().unwrap_or(' ')
+        })
+    }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    /// Sets a cell. Positions outside of the frame are ignored.
+    pub fn set(&mut self, x: usize, y: usize, cell: char) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = cell;
+        }
+    }
+
+    /// Renders the frame with ANSI colors, followed by its caption. Cells without a palette entry
+    /// keep the default color of the terminal.
+    pub fn to_ansi(&self, palette: &Palette) -> String {
+        let colors = palette.colors();
+        let mut s = String::new();
+        for row in self.cells.chunks(self.width.max(1)) {
+            let mut color = None;
+            for &cell in row {
+                let index = palette.index(cell);
+                if color != Some(index) {
+                    match index {
+                        0 => s.push_str(ANSI_RESET),
+                        _ => {
+                            let c = colors[usize::from(index)];
+                            let _ = write!(s, "\x1b[38;2;{};{};{}m", c.r, c.g, c.b);
+                        }
+                    }
+                    color = Some(index);
+                }
+                s.push(cell);
+            }
+            let _ = writeln!(s, "{ANSI_RESET}");
+        }
+        if !self.caption.is_empty() {
+            let _ = writeln!(s, "{ANSI_ITALIC}{}{ANSI_RESET}", self.caption);
+        }
+        s
+    }
+
+    /// Returns the RGB pixels of the frame with each cell drawn as a `scale`×`scale` square.
+    pub fn to_rgb(&self, palette: &Palette, scale: usize) -> Vec<u8> {
+        let colors = palette.colors();
+        self.to_indexed(palette, scale)
+            .into_iter()
+            .flat_map(|i| {
+                let c = colors[usize::from(i)];
+                [c.r, c.g, c.b]
+            })
+            .collect()
+    }
+
+    /// Encodes the frame as a binary PPM image.
+    pub fn to_ppm(&self, palette: &Palette, scale: usize) -> Vec<u8> {
+        let mut ppm =
+            format!("P6\n{} {}\n255\n", self.width * scale, self.height * scale).into_bytes();
+        ppm.extend(self.to_rgb(palette, scale));
+        ppm
+    }
+
+    fn to_indexed(&self, palette: &Palette, scale: usize) -> Vec<u8> {
+        let indices: Vec<u8> = self.cells.iter().map(|&c| palette.index(c)).collect();
+        let mut pixels = Vec::with_capacity(indices.len() * scale * scale);
+        for row in indices.chunks(self.width.max(1)) {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&i| std::iter::repeat_n(i, scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        pixels
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Output {
+    /// Animates the frames in the terminal.
+    Terminal,
+    /// Writes an animated GIF.
+    Gif,
+    /// Writes one PNG image per frame.
+    Png,
+    /// Writes one PPM image per frame.
+    Ppm,
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Output::Terminal => "terminal",
+            Output::Gif => "gif",
+            Output::Png => "png",
+            Output::Ppm => "ppm",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl FromStr for Output {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "terminal" => Ok(Output::Terminal),
+            "gif" => Ok(Output::Gif),
+            "png" => Ok(Output::Png),
+            "ppm" => Ok(Output::Ppm),
+            _ => Err(format!(
+                "unknown output `{s}`, expected terminal, gif, png or ppm."
+            )),
+        }
+    }
+}
+
+/// How recordings are played back, read from `--visualize <output>` and `--fps <n>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Settings {
+    pub output: Output,
+    /// Frames per second of terminal animations and GIFs.
+    pub fps: u32,
+}
+
+impl Settings {
+    /// Parses the visualization flags from command-line arguments.
+    pub fn from_args(args: &[String]) -> Result<Option<Self>, String> {
+        let value = |flag: &str| {
+            args.iter()
+                .position(|x| x == flag)
+                .map(|i| args.get(i + 1).ok_or(format!("missing value for {flag}.")))
+                .transpose()
+        };
+
+        let Some(output) = value("--visualize")? else {
+            return Ok(None);
+        };
+        let fps = match value("--fps")? {
+            Some(fps) => fps
+                .parse()
+                .ok()
+                .filter(|fps| *fps > 0)
+                .ok_or("expected --fps to be a positive integer.")?,
+            None => DEFAULT_FPS,
+        };
+
+        Ok(Some(Self {
+            output: output.parse()?,
+            fps,
+        }))
+    }
+}
+
+/// Reads the [`Settings`] from the command-line of the solution binary.
+pub fn requested() -> Option<Settings> {
+    let args: Vec<String> = env::args().collect();
+    Settings::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    })
+}
+
+/// Whether recorders are active in this process, ignoring bench iterations.
+fn settings() -> Option<Settings> {
+    static WARNED: AtomicBool = AtomicBool::new(false);
+
+    let settings = requested()?;
+    if cfg!(feature = "visualize") {
+        Some(settings)
+    } else {
+        if !WARNED.swap(true, Ordering::Relaxed) {
+            eprintln!("Warning: visualizations need the `visualize` feature. Run `cargo solve <day> --visualize <output>`.");
+        }
+        None
+    }
+}
+
+/// Collects the frames of one visualization. Inactive unless visualizations were requested.
+pub struct Recorder {
+    day: Day,
+    name: String,
+    palette: Palette,
+    settings: Option<Settings>,
+    /// The recorded frames and whether they are key frames.
+    frames: Vec<(Frame, bool)>,
+    /// Only every `stride`th frame is recorded, doubling each time [`MAX_FRAMES`] is exceeded.
+    stride: usize,
+    seen: usize,
+}
+
+impl Recorder {
+    /// Creates a recorder, which writes images to `paths.visualizations` as `<day>-<name>`.
+    pub fn new(day: Day, name: &str, palette: Palette) -> Self {
+        let settings = if is_benching() { None } else { settings() };
+        Self {
+            day,
+            name: name.to_string(),
+            palette,
+            settings,
+            frames: vec![],
+            stride: 1,
+            seen: 0,
+        }
+    }
+
+    /// A recorder that never records, e.g. for nested runs of a simulation.
+    pub fn disabled() -> Self {
+        Self {
+            day: crate::day!(1),
+            name: String::new(),
+            palette: Palette::new(),
+            settings: None,
+            frames: vec![],
+            stride: 1,
+            seen: 0,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.settings.is_some()
+    }
+
+    /// Records the frame built by `frame`. Long recordings skip frames, see [`MAX_FRAMES`].
+    pub fn frame(&mut self, frame: impl FnOnce() -> Frame) {
+        if !self.is_active() {
+            return;
+        }
+        self.seen += 1;
+        if (self.seen - 1).is_multiple_of(self.stride) {
+            self.push(frame(), false);
+        }
+    }
+
+    /// Records the frame built by `frame`, e.g. the final state, even if it would be skipped.
+    pub fn key_frame(&mut self, frame: impl FnOnce() -> Frame) {
+        if self.is_active() {
+            self.seen += 1;
+            self.push(frame(), true);
+        }
+    }
+
+    fn push(&mut self, frame: Frame, is_key: bool) {
+        self.frames.push((frame, is_key));
+
+        if self.frames.len() > MAX_FRAMES {
+            let mut i = 0;
+            self.frames.retain(|(_, is_key)| {
+                i += 1;
+                *is_key || i % 2 == 1
+            });
+            self.stride *= 2;
+        }
+    }
+
+    /// Plays back or writes the recording.
+    pub fn finish(self) {
+        let Some(settings) = self.settings else {
+            return;
+        };
+        if self.frames.is_empty() {
+            return;
+        }
+
+        let result = match settings.output {
+            Output::Terminal => {
+                self.play(settings.fps);
+                return;
+            }
+            Output::Gif => self.write_gif(settings.fps),
+            Output::Png | Output::Ppm => self.write_images(settings.output),
+        };
+
+        match result {
+            Ok(path) => {
+                println!(
+                    "🎞  Wrote {} frames of \"{}\" to \"{}\".",
+                    self.frames.len(),
+                    self.name,
+                    path.display()
+                );
+                println!("{}", self.palette.legend());
+            }
+            Err(e) => eprintln!("Could not write visualization \"{}\": {e}", self.name),
+        }
+    }
+
+    fn play(&self, fps: u32) {
+        let delay = Duration::from_secs(1) / fps;
+        let mut out = stdout();
+        let _ = write!(out, "\x1b[2J");
+
+        for (i, (frame, _)) in self.frames.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "\x1b[H{}{} {ANSI_ITALIC}({}/{}){ANSI_RESET}\x1b[K",
+                frame.to_ansi(&self.palette),
+                self.palette.legend(),
+                i + 1,
+                self.frames.len()
+            );
+            let _ = out.flush();
+            thread::sleep(delay);
+        }
+    }
+
+    fn output_path(&self, extension: &str) -> PathBuf {
+        let name = format!("{}-{}", self.day, self.name);
+        let dir = &Config::get().paths.visualizations;
+        if extension.is_empty() {
+            dir.join(name)
+        } else {
+            dir.join(format!("{name}.{extension}"))
+        }
+    }
+
+    /// Cells are scaled up so that small grids are still visible.
+    fn scale(&self) -> usize {
+        let size = self
+            .frames
+            .iter()
+            .map(|(f, _)| f.width.max(f.height))
+            .max()
+            .unwrap_or(1);
+        (512 / size.max(1)).clamp(1, 16)
+    }
+
+    fn write_images(&self, output: Output) -> io::Result<PathBuf> {
+        let dir = self.output_path("");
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+
+        let scale = self.scale();
+        for (i, (frame, _)) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("{:04}", i + 1));
+            match output {
+                Output::Png => write_png(&path.with_extension("png"), frame, &self.palette, scale)?,
+                _ => fs::write(
+                    path.with_extension("ppm"),
+                    frame.to_ppm(&self.palette, scale),
+                )?,
+            }
+        }
+
+        Ok(dir)
+    }
+
+    #[cfg(feature = "visualize")]
+    fn write_gif(&self, fps: u32) -> io::Result<PathBuf> {
+        let path = self.output_path("gif");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let scale = self.scale();
+        let frames: Vec<&Frame> = self.frames.iter().map(|(f, _)| f).collect();
+        let size = |n: usize| u16::try_from(n * scale).map_err(io::Error::other);
+        let width = size(frames.iter().map(|f| f.width).max().unwrap_or(0))?;
+        let height = size(frames.iter().map(|f| f.height).max().unwrap_or(0))?;
+        let palette: Vec<u8> = self
+            .palette
+            .colors()
+            .iter()
+            .flat_map(|c| [c.r, c.g, c.b])
+            .collect();
+
+        let file = io::BufWriter::new(fs::File::create(&path)?);
+        let mut encoder =
+            gif::Encoder::new(file, width, height, &palette).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        // GIF delays are in hundredths of a second, browsers treat less than 2 as 10.
+        let delay = u16::try_from((100 / fps).max(2)).unwrap_or(u16::MAX);
+        for frame in frames {
+            let mut gif_frame = gif::Frame::from_indexed_pixels(
+                size(frame.width)?,
+                size(frame.height)?,
+                frame.to_indexed(&self.palette, scale),
+                None,
+            );
+            gif_frame.delay = delay;
+            encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+        }
+
+        Ok(path)
+    }
+
+    #[cfg(not(feature = "visualize"))]
+    fn write_gif(&self, _fps: u32) -> io::Result<PathBuf> {
+        unreachable!("recorders are inactive without the `visualize` feature")
+    }
+}
+
+#[cfg(feature = "visualize")]
+fn write_png(path: &Path, frame: &Frame, palette: &Palette, scale: usize) -> io::Result<()> {
+    let size = |n: usize| u32::try_from(n * scale).map_err(io::Error::other);
+    let file = io::BufWriter::new(fs::File::create(path)?);
+    let mut encoder = png::Encoder::new(file, size(frame.width)?, size(frame.height)?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&frame.to_rgb(palette, scale)))
+        .map_err(io::Error::other)
+}
+
+#[cfg(not(feature = "visualize"))]
+fn write_png(_path: &Path, _frame: &Frame, _palette: &Palette, _scale: usize) -> io::Result<()> {
+    unreachable!("recorders are inactive without the `visualize` feature")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Color, Frame, Output, Palette, Recorder, Settings, MAX_FRAMES};
+
+    fn palette() -> Palette {
+        Palette::new()
+            .with('#', Color::GRAY, "wall")
+            .with('@', Color::RED, "robot")
+    }
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_settings() {
+        assert_eq!(Settings::from_args(&args("01 --time")), Ok(None));
+        assert_eq!(
+            Settings::from_args(&args("01 --visualize gif --fps 30")),
+            Ok(Some(Settings {
+                output: Output::Gif,
+                fps: 30
+            }))
+        );
+        assert_eq!(
+            Settings::from_args(&args("01 --visualize terminal")).map(|s| s.unwrap().fps),
+            Ok(10)
+        );
+        assert!(Settings::from_args(&args("01 --visualize mp4")).is_err());
+        assert!(Settings::from_args(&args("01 --visualize terminal --fps 0")).is_err());
+        assert!(Settings::from_args(&args("01 --visualize")).is_err());
+    }
+
+    #[test]
+    fn builds_frames() {
+        let mut frame = Frame::parse("#.#\n#@");
+        assert_eq!((frame.width, frame.height), (3, 2));
+        assert_eq!(frame.get(2, 1), Some(' '));
+        frame.set(2, 1, '#');
+        frame.set(5, 5, '#');
+        assert_eq!(frame, Frame::parse("#.#\n#@#"));
+        assert_eq!(frame.get(3, 0), None);
+    }
+
+    #[test]
+    fn encodes_images() {
+        let frame = Frame::parse("#@");
+        let palette = palette();
+        assert_eq!(
+            palette.colors(),
+            vec![Color::BLACK, Color::GRAY, Color::RED]
+        );
+        assert_eq!(frame.to_indexed(&palette, 2), vec![1, 1, 2, 2, 1, 1, 2, 2]);
+        assert_eq!(frame.to_rgb(&palette, 1), vec![110, 110, 120, 220, 50, 47]);
+
+        let ppm = frame.to_ppm(&palette, 1);
+        assert!(ppm.starts_with(b"P6\n2 1\n255\n"));
+        assert_eq!(ppm.len(), 11 + 6);
+    }
+
+    #[test]
+    fn renders_ansi() {
+        let rendered = Frame::parse("##@.").caption("t=1").to_ansi(&palette());
+        assert_eq!(
+            rendered,
+            "\x1b[38;2;110;110;120m##\x1b[38;2;220;50;47m@\x1b[0m.\x1b[0m\n\x1b[3mt=1\x1b[0m\n"
+        );
+
+        let legend = palette().with('O', Color::GRAY, "wall").legend();
+        assert_eq!(
+            legend,
+            "\x1b[38;2;110;110;120m#\x1b[0m\x1b[38;2;110;110;120mO\x1b[0m wall   \x1b[38;2;220;50;47m@\x1b[0m robot"
+        );
+    }
+
+    #[test]
+    fn stays_inactive_without_request() {
+        let mut recorder = Recorder::new(crate::day!(6), "test", palette());
+        assert!(!recorder.is_active());
+        recorder.frame(|| unreachable!());
+        recorder.key_frame(|| unreachable!());
+        recorder.finish();
+    }
+
+    #[test]
+    fn thins_out_long_recordings() {
+        let mut recorder = Recorder::disabled();
+        recorder.settings = Some(Settings {
+            output: Output::Terminal,
+            fps: 10,
+        });
+
+        for i in 0..(MAX_FRAMES * 3) {
+            recorder.frame(|| Frame::parse(&i.to_string()));
+        }
+        recorder.key_frame(|| Frame::parse("end"));
+
+        assert!(recorder.frames.len() <= MAX_FRAMES + 1);
+        assert_eq!(recorder.stride, 4);
+        assert_eq!(recorder.frames[1].0, Frame::parse("4"));
+        assert!(recorder.frames.last().unwrap().1);
+    }
+}